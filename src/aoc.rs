@@ -1,6 +1,19 @@
+pub mod answer;
+pub mod baseline;
+pub mod cancel;
+pub mod error;
 pub mod grid;
-
-use grid::Grid;
+pub mod manifest;
+pub mod puzzle;
+pub mod report;
+pub mod scaffold;
+pub mod stats;
+pub mod store;
+
+use answer::Answer;
+use error::AocError;
+use grid::{Edges, Grid, Offset};
+use puzzle::Puzzle;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
@@ -9,59 +22,225 @@ use std::fmt::Debug;
 use std::fmt::{Display, Formatter};
 use std::fs::File;
 use std::io::{self, BufRead, BufReader, Lines, Read};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
+use store::InputStore;
+
+pub trait Solution: Debug + Send {
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>>;
+
+    /// Solve part 1 from the parsed input. Parts may be run in either order, or on their own, so
+    /// neither may depend on changes the other makes to the solution's state.
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>>;
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>>;
+
+    /// Solve both parts.
+    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::Both(self.part1()?, self.part2()?))
+    }
+
+    /// The example inputs from the puzzle text and their expected answers.
+    fn examples(&self) -> Vec<Example> {
+        Vec::new()
+    }
 
-pub trait Solution: Debug {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>>;
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>>;
-    fn run(&mut self, num: usize) -> Result<SolutionData, Box<dyn Error>> {
+    /// Switch any puzzle parameters (grid size, step count, etc.) that differ between the
+    /// examples and the real input. Must be called before `parse_input`.
+    fn set_mode(&mut self, _mode: Mode) {}
+
+    /// Parse the input and solve both parts, or only the given one, timing each phase.
+    fn run(
+        &mut self,
+        num: usize,
+        input: &Input,
+        part: Option<usize>,
+    ) -> Result<SolutionData, Box<dyn Error>> {
         let parse_timer = Instant::now();
-        self.parse_input()?;
+        self.parse_input(input)
+            .map_err(|e| AocError::from_input(e, input))?;
         let parse_time = parse_timer.elapsed();
 
-        let solution_timer = Instant::now();
-        let answers = self.solve()?;
-        let solution_time = solution_timer.elapsed();
+        let mut part1 = None;
+        let mut part1_time = Duration::ZERO;
+        if part != Some(2) {
+            let part1_timer = Instant::now();
+            part1 = Some(self.part1()?);
+            part1_time = part1_timer.elapsed();
+        }
+
+        let mut part2 = None;
+        let mut part2_time = Duration::ZERO;
+        if part != Some(1) {
+            let part2_timer = Instant::now();
+            part2 = Some(self.part2()?);
+            part2_time = part2_timer.elapsed();
+        }
 
-        Ok(SolutionData::new(num, answers, parse_time, solution_time))
+        Ok(SolutionData::new(
+            num,
+            Answers::from_parts(part1, part2),
+            parse_time,
+            part1_time,
+            part2_time,
+        ))
     }
 }
 
-// Suppress warnings since Part1 and None will only be used early in solutions.
-#[allow(dead_code)]
-#[derive(PartialEq, Eq, Debug)]
+/// The input a day's puzzle is read from when no other file is given, from the input store
+/// configured by the environment.
+pub fn default_input(year: usize, day: usize) -> Result<Input, Box<dyn Error>> {
+    Ok(InputStore::from_env()?.input(year, day)?)
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Mode {
+    Live,
+    Example,
+}
+
+#[derive(Debug)]
+pub struct Example {
+    /// The example's input, relative to the root of the crate.
+    pub filename: &'static str,
+    pub answers: Answers,
+}
+
+impl Example {
+    pub fn new(filename: &'static str, answers: Answers) -> Example {
+        Example { filename, answers }
+    }
+
+    /// The example's input, found in the crate's source tree wherever the program is run from.
+    pub fn input(&self) -> Input {
+        Input::file(Path::new(env!("CARGO_MANIFEST_DIR")).join(self.filename))
+    }
+
+    /// Solve the example with a fresh solution in example mode. Only the parts the example has
+    /// answers for are run, since the other part may not apply to the example input.
+    pub fn run(
+        &self,
+        puzzle: &Puzzle,
+        part: Option<usize>,
+    ) -> Result<SolutionData, Box<dyn Error>> {
+        let part = match self.answers.only(part) {
+            Answers::Part1(_) => Some(1),
+            Answers::Part2(_) => Some(2),
+            _ => part,
+        };
+        let mut solution = puzzle.solution();
+        solution.set_mode(Mode::Example);
+        solution.run(puzzle.day, &self.input(), part)
+    }
+}
+
+/// Run every example a puzzle declares, for tests, failing if there are none or any answer is
+/// wrong.
+pub fn check_examples(puzzle: &Puzzle) -> Result<(), Box<dyn Error>> {
+    let examples = puzzle.solution().examples();
+    if examples.is_empty() {
+        return Err(format!("{puzzle} has no examples").into());
+    }
+    for example in examples {
+        let datum = example.run(puzzle, None)?;
+        if !datum.answers.matches(&example.answers) {
+            return Err(format!(
+                "{}: got\n{}\nexpected\n{}",
+                example.filename, datum.answers, example.answers
+            )
+            .into());
+        }
+    }
+    Ok(())
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Answers {
-    Both(String, String),
-    Part1(String),
+    Both(Answer, Answer),
+    Part1(Answer),
+    /// Only the second part, e.g. when just part 2 was requested.
+    Part2(Answer),
     None,
 }
 
 impl Display for Answers {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        match self {
-            Self::Both(part1, part2) => write!(f, "Part 1: {part1}\nPart 2: {part2}"),
-            Self::Part1(part1) => write!(f, "Part 1: {part1}"),
-            Self::None => Ok(()),
+        let mut separator = "";
+        for part in 1..=2 {
+            if let Some(answer) = self.part(part) {
+                // Start multi-line answers on their own line so they line up.
+                let gap = if answer.is_multiline() { "\n" } else { " " };
+                write!(f, "{separator}Part {part}:{gap}{answer}")?;
+                separator = "\n";
+            }
         }
+        Ok(())
     }
 }
 
-// Suppress warnings since Part1 and None will only be used early in solutions.
-#[allow(dead_code)]
 impl Answers {
-    pub fn both<T: Display, U: Display>(part1: T, part2: U) -> Answers {
-        Answers::Both(format!("{part1}"), format!("{part2}"))
+    pub fn both<T: Into<Answer>, U: Into<Answer>>(part1: T, part2: U) -> Answers {
+        Answers::Both(part1.into(), part2.into())
+    }
+
+    pub fn part1<T: Into<Answer>>(part1: T) -> Answers {
+        Answers::Part1(part1.into())
+    }
+
+    pub fn part2<T: Into<Answer>>(part2: T) -> Answers {
+        Answers::Part2(part2.into())
     }
 
-    pub fn part1<T: Display>(part1: T) -> Answers {
-        Answers::Part1(format!("{part1}"))
+    pub fn from_parts(part1: Option<Answer>, part2: Option<Answer>) -> Answers {
+        match (part1, part2) {
+            (Some(part1), Some(part2)) => Answers::Both(part1, part2),
+            (Some(part1), None) => Answers::Part1(part1),
+            (None, Some(part2)) => Answers::Part2(part2),
+            (None, None) => Answers::None,
+        }
     }
 
     pub fn complete(&self) -> bool {
-        match self {
-            Self::Both(_, _) => true,
-            _ => false,
+        matches!(self, Self::Both(_, _))
+    }
+
+    pub fn part(&self, part: usize) -> Option<&Answer> {
+        match (self, part) {
+            (Self::Both(part1, _) | Self::Part1(part1), 1) => Some(part1),
+            (Self::Both(_, part2) | Self::Part2(part2), 2) => Some(part2),
+            _ => None,
+        }
+    }
+
+    /// Keep only the given part, or every part if `None`.
+    pub fn only(&self, part: Option<usize>) -> Answers {
+        match (part, self.part(1), self.part(2)) {
+            (None, _, _) => self.clone(),
+            (Some(1), Some(part1), _) => Answers::Part1(part1.clone()),
+            (Some(2), _, Some(part2)) => Answers::Part2(part2.clone()),
+            _ => Answers::None,
+        }
+    }
+
+    /// Check these answers against an expectation, ignoring any parts the expectation omits.
+    ///
+    /// ```
+    /// use aoc2024::aoc::Answers;
+    ///
+    /// let answers = Answers::both(11, "4,6,3");
+    /// assert!(answers.matches(&Answers::part1(11u64)));
+    /// assert!(answers.matches(&Answers::part2("4,6,3")));
+    /// assert!(!answers.matches(&Answers::both(11, 31)));
+    /// ```
+    pub fn matches(&self, expected: &Answers) -> bool {
+        match (self, expected) {
+            (_, Self::None) => true,
+            (Self::Both(part1, _) | Self::Part1(part1), Self::Part1(expected1)) => {
+                part1 == expected1
+            }
+            (Self::Both(_, part2) | Self::Part2(part2), Self::Part2(expected2)) => {
+                part2 == expected2
+            }
+            _ => self == expected,
         }
     }
 }
@@ -71,7 +250,11 @@ pub struct SolutionData {
     pub num: usize,
     pub answers: Answers,
     pub parse_time: Duration,
-    pub solve_time: Duration,
+    /// Time spent in each part, which is zero for a part that was not run.
+    pub part1_time: Duration,
+    pub part2_time: Duration,
+    /// Why the day failed, if it did, in which case it has no answers or timings.
+    pub error: Option<String>,
 }
 
 impl SolutionData {
@@ -79,95 +262,161 @@ impl SolutionData {
         num: usize,
         answers: Answers,
         parse_time: Duration,
-        solve_time: Duration,
+        part1_time: Duration,
+        part2_time: Duration,
     ) -> SolutionData {
         SolutionData {
             num,
             answers,
             parse_time,
-            solve_time,
+            part1_time,
+            part2_time,
+            error: None,
         }
     }
+
+    /// A day that could not be solved, because its input was missing or malformed or its
+    /// solution returned an error or panicked.
+    pub fn failed<S: Into<String>>(num: usize, error: S) -> SolutionData {
+        SolutionData {
+            error: Some(error.into()),
+            ..SolutionData::new(
+                num,
+                Answers::None,
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::ZERO,
+            )
+        }
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// The time spent solving both parts.
+    pub fn solve_time(&self) -> Duration {
+        self.part1_time + self.part2_time
+    }
 }
 
 impl Display for SolutionData {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         let parse_millis = self.parse_time.as_micros() as f64 / 1000.0;
-        let solve_millis = self.solve_time.as_micros() as f64 / 1000.0;
-        write!(f, "~- DAY {:0>2} -~\n", self.num)?;
-        write!(
+        let solve_millis = self.solve_time().as_micros() as f64 / 1000.0;
+        let part1_millis = self.part1_time.as_micros() as f64 / 1000.0;
+        let part2_millis = self.part2_time.as_micros() as f64 / 1000.0;
+        writeln!(f, "~- DAY {:0>2} -~", self.num)?;
+        if let Some(error) = &self.error {
+            return write!(f, "FAILED: {error}");
+        }
+        writeln!(
             f,
-            "Parse: {parse_millis:.3}ms, Solve: {solve_millis:.3}ms\n"
+            "Parse: {parse_millis:.3}ms, Solve: {solve_millis:.3}ms \
+             (part 1 {part1_millis:.3}ms, part 2 {part2_millis:.3}ms)"
         )?;
         write!(f, "{}", self.answers)
     }
 }
 
-pub struct Statistics<'a> {
-    pub total_time: Duration,
-    pub complete: Vec<&'a SolutionData>,
-    pub avg: f64,
-    pub stddev: f64,
+/// Where a puzzle input is read from. Inputs can be opened more than once, except for stdin
+/// unless it is `buffered` first.
+///
+/// ```
+/// use aoc2024::aoc::{read_lines, Input};
+///
+/// let input = Input::text("1 2\n3 4\n");
+/// let lines = read_lines(&input)?.collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(lines, ["1 2", "3 4"]);
+/// assert_eq!(input.to_string(), "<memory>");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub enum Input {
+    File(PathBuf),
+    Stdin,
+    /// Stdin after it has been read into memory, which still reports itself as stdin.
+    BufferedStdin(Vec<u8>),
+    Memory(Vec<u8>),
 }
 
-impl Statistics<'_> {
-    pub fn calc(data: &Vec<SolutionData>) -> Statistics {
-        let total_time = data.iter().fold(Duration::from_secs(0), |a, s| {
-            a + s.parse_time + s.solve_time
-        });
-        let complete: Vec<_> = data.iter().filter(|s| s.answers.complete()).collect();
-        let completed = complete.len();
-        let avg = complete
-            .iter()
-            .fold(Duration::from_secs(0), |a, s| {
-                a + s.parse_time + s.solve_time
-            })
-            .as_micros() as f64
-            / completed as f64;
-        let stddev = complete
-            .iter()
-            .fold(0.0, |a, s| {
-                a + (s.parse_time.as_micros() as f64 + s.solve_time.as_micros() as f64 - avg)
-                    .powf(2.0)
-            })
-            .sqrt()
-            / completed as f64;
-        Statistics {
-            total_time,
-            complete,
-            avg,
-            stddev,
+impl Input {
+    pub fn file<P: AsRef<Path>>(path: P) -> Input {
+        Input::File(path.as_ref().to_path_buf())
+    }
+
+    /// Interpret a command-line argument, where `-` means stdin.
+    pub fn from_arg<P: AsRef<Path>>(arg: P) -> Input {
+        let path = arg.as_ref();
+        if path.as_os_str() == "-" {
+            Input::Stdin
+        } else {
+            Input::file(path)
+        }
+    }
+
+    pub fn text(text: &str) -> Input {
+        Input::Memory(text.as_bytes().to_vec())
+    }
+
+    pub fn bytes(bytes: Vec<u8>) -> Input {
+        Input::Memory(bytes)
+    }
+
+    /// Read stdin into memory so that the input can be opened more than once.
+    pub fn buffered(self) -> io::Result<Input> {
+        match self {
+            Self::Stdin => {
+                let mut bytes = Vec::new();
+                io::stdin().lock().read_to_end(&mut bytes)?;
+                Ok(Input::BufferedStdin(bytes))
+            }
+            input => Ok(input),
         }
     }
+
+    pub fn open(&self) -> io::Result<Box<dyn BufRead + '_>> {
+        Ok(match self {
+            Self::File(path) => Box::new(BufReader::new(File::open(path)?)),
+            Self::Stdin => Box::new(io::stdin().lock()),
+            Self::BufferedStdin(bytes) | Self::Memory(bytes) => Box::new(bytes.as_slice()),
+        })
+    }
 }
 
-impl Display for Statistics<'_> {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "~- STATISTICS -~\n")?;
-        write!(
-            f,
-            "T = {}ms, n = {}, μ = {:.2}ms, σ = {:.2}ms",
-            self.total_time.as_millis(),
-            self.complete.len(),
-            self.avg / 1000.0,
-            self.stddev / 1000.0
-        )
+impl Display for Input {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin | Self::BufferedStdin(_) => write!(f, "<stdin>"),
+            Self::Memory(_) => write!(f, "<memory>"),
+        }
     }
 }
 
-pub struct FileCharIterator {
-    reader: BufReader<File>,
+/// The characters of an input, one byte at a time, as returned by `read_chars`.
+///
+/// ```
+/// use aoc2024::aoc::{CharIterator, Input};
+///
+/// let input = Input::text("ab\n");
+/// let mut chars = CharIterator::new(&input)?;
+/// assert_eq!(chars.next().transpose()?, Some('a'));
+/// assert_eq!(chars.count(), 2);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct CharIterator<'a> {
+    reader: Box<dyn BufRead + 'a>,
 }
 
-impl FileCharIterator {
-    pub fn new<P: AsRef<Path>>(path: P) -> io::Result<Self> {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-        Ok(FileCharIterator { reader })
+impl<'a> CharIterator<'a> {
+    pub fn new(input: &'a Input) -> io::Result<Self> {
+        let reader = input.open()?;
+        Ok(CharIterator { reader })
     }
 }
 
-impl Iterator for FileCharIterator {
+impl Iterator for CharIterator<'_> {
     type Item = io::Result<char>;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -180,23 +429,58 @@ impl Iterator for FileCharIterator {
     }
 }
 
-pub fn read_chars<P>(path: P) -> io::Result<FileCharIterator>
-where
-    P: AsRef<Path>,
-{
-    FileCharIterator::new(path)
+/// Read the input one byte at a time, as characters, including line breaks.
+///
+/// ```
+/// use aoc2024::aoc::{read_chars, Input};
+///
+/// let chars = read_chars(&Input::text("#.\n"))?.collect::<Result<String, _>>()?;
+/// assert_eq!(chars, "#.\n");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn read_chars(input: &Input) -> io::Result<CharIterator<'_>> {
+    CharIterator::new(input)
+}
+
+/// Read the input line by line, without the line breaks.
+///
+/// ```
+/// use aoc2024::aoc::{read_lines, Input};
+///
+/// let input = Input::text("3   4\n4   3\n");
+/// let lines = read_lines(&input)?.collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(lines, ["3   4", "4   3"]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn read_lines(input: &Input) -> io::Result<Lines<Box<dyn BufRead + '_>>> {
+    Ok(input.open()?.lines())
 }
 
-pub fn read_lines<P>(filename: P) -> io::Result<Lines<BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+/// Read the whole input into a string.
+///
+/// ```
+/// use aoc2024::aoc::{read_to_string, Input};
+///
+/// assert_eq!(read_to_string(&Input::text("xmas\n"))?, "xmas\n");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn read_to_string(input: &Input) -> io::Result<String> {
+    let mut content = String::new();
+    input.open()?.read_to_string(&mut content)?;
+    Ok(content)
 }
 
 pub type Position = (usize, usize);
 
+/// A direction on a grid, where up is towards row 0.
+///
+/// ```
+/// use aoc2024::aoc::Direction;
+///
+/// let offsets = Direction::all().map(|d| d.offset());
+/// assert_eq!(offsets, [(0, -1), (0, 1), (-1, 0), (1, 0)]);
+/// assert_eq!(Direction::Left.left(), Direction::Down);
+/// ```
 #[derive(Ord, PartialOrd, Eq, PartialEq, Hash, Copy, Clone, Debug)]
 pub enum Direction {
     Up,
@@ -210,6 +494,24 @@ impl Direction {
         [Self::Up, Self::Down, Self::Left, Self::Right]
     }
 
+    /// The step to the next cell in this direction.
+    pub fn offset(&self) -> Offset {
+        match self {
+            Self::Up => (0, -1),
+            Self::Down => (0, 1),
+            Self::Left => (-1, 0),
+            Self::Right => (1, 0),
+        }
+    }
+
+    /// The direction after turning 90 degrees clockwise.
+    ///
+    /// ```
+    /// use aoc2024::aoc::Direction;
+    ///
+    /// assert_eq!(Direction::Up.right(), Direction::Right);
+    /// assert_eq!(Direction::Up.right().left(), Direction::Up);
+    /// ```
     pub fn right(&self) -> Direction {
         match self {
             Self::Up => Self::Right,
@@ -219,6 +521,7 @@ impl Direction {
         }
     }
 
+    /// The direction after turning 90 degrees anticlockwise.
     pub fn left(&self) -> Direction {
         match self {
             Self::Up => Self::Left,
@@ -229,6 +532,35 @@ impl Direction {
     }
 }
 
+/// A rectangular map of cells, with pathfinding between open cells.
+///
+/// ```
+/// use aoc2024::aoc::grid::Grid;
+/// use aoc2024::aoc::{Map, MapDisplay, Position};
+///
+/// struct Maze(Grid<char>);
+///
+/// impl Map for Maze {
+///     type Cell = char;
+///     fn width(&self) -> usize {
+///         self.0.width
+///     }
+///     fn height(&self) -> usize {
+///         self.0.height
+///     }
+///     fn get(&self, pos: &Position) -> Option<&char> {
+///         self.0.get(pos)
+///     }
+/// }
+///
+/// let mut grid = Grid::new();
+/// grid.extend("..#.#...".chars());
+/// grid.width = 4;
+/// grid.height = 2;
+/// let maze = Maze(grid);
+/// assert_eq!(maze.pathfind('.', (0, 0), (3, 1)).0, Some(4));
+/// assert_eq!(MapDisplay(&maze).to_string(), "..#.\n#...");
+/// ```
 pub trait Map {
     type Cell: Display + Eq;
     fn width(&self) -> usize;
@@ -308,43 +640,38 @@ pub trait Map {
         (lows.get(&end).copied(), lows, backtracks)
     }
 
+    /// The cell one step from `pos` in direction `dir`, with its position, if it is on the map.
+    ///
+    /// ```
+    /// use aoc2024::aoc::grid::Grid;
+    /// use aoc2024::aoc::{Direction, Map, Position};
+    ///
+    /// struct Line(Grid<u8>);
+    ///
+    /// impl Map for Line {
+    ///     type Cell = u8;
+    ///     fn width(&self) -> usize {
+    ///         self.0.width
+    ///     }
+    ///     fn height(&self) -> usize {
+    ///         self.0.height
+    ///     }
+    ///     fn get(&self, pos: &Position) -> Option<&u8> {
+    ///         self.0.get(pos)
+    ///     }
+    /// }
+    ///
+    /// let line = Line(Grid::from_fn(3, 1, |(x, _)| x as u8));
+    /// assert_eq!(line.go(Direction::Right, &(1, 0)), Some(((2, 0), &2)));
+    /// assert_eq!(line.go(Direction::Up, &(1, 0)), None);
+    /// ```
     fn go(&self, dir: Direction, pos: &Position) -> Option<(Position, &Self::Cell)> {
-        let (x, y) = *pos;
-        let width = self.width();
-        let height = self.height();
-        let new = match dir {
-            Direction::Up => {
-                if y == 0 {
-                    return None;
-                }
-                (x, y - 1)
-            }
-            Direction::Down => {
-                if y == height - 1 {
-                    return None;
-                }
-                (x, y + 1)
-            }
-            Direction::Left => {
-                if x == 0 {
-                    return None;
-                }
-                (x - 1, y)
-            }
-            Direction::Right => {
-                if x == width - 1 {
-                    return None;
-                }
-                (x + 1, y)
-            }
-        };
-        if let Some(state) = self.get(&new) {
-            return Some((new, state));
-        }
-        None
+        let new = Edges::Bounded.step(*pos, dir.offset(), self.width(), self.height())?;
+        Some((new, self.get(&new)?))
     }
 }
 
+/// Draws a map one row per line, for debugging.
 pub struct MapDisplay<'a, T: Map>(pub &'a T);
 
 impl<'a, T: Map> Display for MapDisplay<'a, T> {
@@ -356,7 +683,7 @@ impl<'a, T: Map> Display for MapDisplay<'a, T> {
                 write!(f, "{}", self.0.get(&(x, y)).unwrap())?;
             }
             if y != height - 1 {
-                write!(f, "\n")?;
+                writeln!(f)?;
             }
         }
         Ok(())
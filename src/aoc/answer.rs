@@ -0,0 +1,127 @@
+use std::fmt::{Display, Formatter};
+
+/// The answer to one part of a puzzle. Integers compare equal whatever type a solution computed
+/// them with, and text that looks like an integer is treated as one, so answers can be compared
+/// against expectations parsed from text.
+///
+/// ```
+/// use aoc2024::aoc::answer::Answer;
+///
+/// assert_eq!(Answer::from(42u8), Answer::from(42i64));
+/// assert_eq!(Answer::parse("42"), 42.into());
+/// assert_eq!(Answer::parse("4,6,3").to_string(), "4,6,3");
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Answer {
+    Int(i128),
+    /// An integer too large for `Int`. Smaller values are always stored as `Int`.
+    BigInt(u128),
+    Text(String),
+    /// A visual answer spanning several lines, e.g. letters drawn on a grid.
+    Lines(Vec<String>),
+}
+
+impl Answer {
+    /// Interpret text as an answer, which is the inverse of its `Display` implementation.
+    pub fn parse(text: &str) -> Answer {
+        let trimmed = text.trim();
+        if let Ok(n) = trimmed.parse::<i128>() {
+            return Answer::Int(n);
+        }
+        if let Ok(n) = trimmed.parse::<u128>() {
+            return Answer::BigInt(n);
+        }
+        let text = text.strip_suffix('\n').unwrap_or(text);
+        if text.contains('\n') {
+            Answer::lines(text.lines())
+        } else {
+            Answer::Text(text.to_string())
+        }
+    }
+
+    pub fn lines<I, S>(lines: I) -> Answer
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Answer::Lines(lines.into_iter().map(|l| l.into()).collect())
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Self::Lines(_))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Int(n) => write!(f, "{n}"),
+            Self::BigInt(n) => write!(f, "{n}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Int(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Answer {
+        match i128::try_from(n) {
+            Ok(n) => Answer::Int(n),
+            Err(_) => Answer::BigInt(n),
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::parse(text)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::parse(&text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn equality() {
+        assert_eq!(Answer::from(4826usize), Answer::from(4826i64));
+        assert_eq!(Answer::from(-3i32), Answer::parse("-3"));
+        assert_eq!(Answer::from(117440u128), Answer::from("117440"));
+        assert_eq!(
+            Answer::from(u128::MAX),
+            Answer::parse(&u128::MAX.to_string())
+        );
+        assert_ne!(Answer::from(1), Answer::from("1,2"));
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Answer::parse(" 42\n"), Answer::Int(42));
+        assert_eq!(Answer::parse("6,1"), Answer::Text("6,1".to_string()));
+        let lines = Answer::parse("#..#\n####\n");
+        assert_eq!(lines, Answer::lines(["#..#", "####"]));
+        assert_eq!(lines.to_string(), "#..#\n####");
+        for answer in [Answer::from(u128::MAX), Answer::from("co,de,ka,ta"), lines] {
+            assert_eq!(Answer::parse(&answer.to_string()), answer);
+        }
+    }
+}
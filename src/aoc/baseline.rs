@@ -0,0 +1,283 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+use crate::aoc::stats::Summary;
+use crate::aoc::SolutionData;
+
+const HEADER: &str = "year,day,parse_ns,parse_stddev_ns,solve_ns,solve_stddev_ns";
+
+/// The typical time for one phase of a solution. The spread is zero when the timing comes from a
+/// single run.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Phase {
+    pub mean_ns: f64,
+    pub stddev_ns: f64,
+}
+
+impl Phase {
+    pub fn from_summary(summary: &Summary) -> Phase {
+        Phase {
+            mean_ns: summary.mean * 1e6,
+            stddev_ns: summary.sample_stddev() * 1e6,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Timing {
+    pub parse: Phase,
+    pub solve: Phase,
+}
+
+/// Per-day timings saved from one run so that later runs can be compared against them. Days are
+/// keyed by year as well, so that a baseline from one year is never compared against another.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    timings: BTreeMap<(usize, usize), Timing>,
+}
+
+impl Baseline {
+    /// The timings of the days in `data`, which are all from `year`.
+    pub fn from_data(year: usize, data: &[SolutionData]) -> Baseline {
+        let mut baseline = Baseline::default();
+        for datum in data.iter().filter(|datum| !datum.is_failed()) {
+            let phase = |time: std::time::Duration| Phase {
+                mean_ns: time.as_nanos() as f64,
+                stddev_ns: 0.0,
+            };
+            baseline.insert(
+                year,
+                datum.num,
+                Timing {
+                    parse: phase(datum.parse_time),
+                    solve: phase(datum.solve_time()),
+                },
+            );
+        }
+        baseline
+    }
+
+    pub fn insert(&mut self, year: usize, num: usize, timing: Timing) {
+        self.timings.insert((year, num), timing);
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Baseline, Box<dyn Error>> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+        Baseline::parse(&content).map_err(|e| format!("{}: {e}", path.display()).into())
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn Error>> {
+        let path = path.as_ref();
+        fs::write(path, self.to_csv())
+            .map_err(|e| format!("could not write {}: {e}", path.display()).into())
+    }
+
+    pub fn parse(content: &str) -> Result<Baseline, String> {
+        let mut baseline = Baseline::default();
+        for (i, line) in content.lines().enumerate() {
+            if line.is_empty() || line == HEADER {
+                continue;
+            }
+            let fields = line
+                .split(',')
+                .map(|f| f.trim().parse::<f64>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("line {}: {e}", i + 1))?;
+            let [year, num, parse, parse_stddev, solve, solve_stddev] = fields[..] else {
+                return Err(format!("line {}: expected 6 fields", i + 1));
+            };
+            baseline.insert(
+                year as usize,
+                num as usize,
+                Timing {
+                    parse: Phase {
+                        mean_ns: parse,
+                        stddev_ns: parse_stddev,
+                    },
+                    solve: Phase {
+                        mean_ns: solve,
+                        stddev_ns: solve_stddev,
+                    },
+                },
+            );
+        }
+        Ok(baseline)
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{HEADER}\n");
+        for ((year, num), timing) in &self.timings {
+            csv.push_str(&format!(
+                "{year},{num},{:.0},{:.0},{:.0},{:.0}\n",
+                timing.parse.mean_ns,
+                timing.parse.stddev_ns,
+                timing.solve.mean_ns,
+                timing.solve.stddev_ns
+            ));
+        }
+        csv
+    }
+
+    /// Compare a later run against this baseline, phase by phase, for the days present in both.
+    pub fn compare(&self, current: &Baseline, tolerance: &Tolerance) -> Vec<Change> {
+        let mut changes = Vec::new();
+        for (&(year, num), after) in &current.timings {
+            if let Some(before) = self.timings.get(&(year, num)) {
+                for (phase, before, after) in [
+                    ("parse", before.parse, after.parse),
+                    ("solve", before.solve, after.solve),
+                ] {
+                    let mut change = Change {
+                        year,
+                        num,
+                        phase,
+                        before,
+                        after,
+                        regressed: false,
+                    };
+                    change.regressed = tolerance.exceeded_by(&change);
+                    changes.push(change);
+                }
+            }
+        }
+        changes
+    }
+}
+
+/// How much slower a phase may get before it counts as a regression. A phase regresses when it is
+/// slower by more than `percent`, or, if `sigma` is set and the baseline recorded a spread, by
+/// more than `sigma` standard deviations.
+#[derive(Clone, Copy, Debug)]
+pub struct Tolerance {
+    pub percent: f64,
+    pub sigma: Option<f64>,
+}
+
+impl Tolerance {
+    fn exceeded_by(&self, change: &Change) -> bool {
+        let beyond_sigma = match (self.sigma, change.sigma()) {
+            (Some(max), Some(sigma)) => sigma > max,
+            _ => false,
+        };
+        change.percent() > self.percent || beyond_sigma
+    }
+}
+
+#[derive(Debug)]
+pub struct Change {
+    pub year: usize,
+    pub num: usize,
+    pub phase: &'static str,
+    pub before: Phase,
+    pub after: Phase,
+    pub regressed: bool,
+}
+
+impl Change {
+    /// The relative change in the mean time as a percentage.
+    pub fn percent(&self) -> f64 {
+        if self.before.mean_ns == 0.0 {
+            return 0.0;
+        }
+        (self.after.mean_ns - self.before.mean_ns) / self.before.mean_ns * 100.0
+    }
+
+    /// The change in the mean time in baseline standard deviations, if the baseline has a spread.
+    pub fn sigma(&self) -> Option<f64> {
+        if self.before.stddev_ns > 0.0 {
+            Some((self.after.mean_ns - self.before.mean_ns) / self.before.stddev_ns)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} DAY {:0>2} {}: {:.3}ms -> {:.3}ms ({:+.1}%",
+            self.year,
+            self.num,
+            self.phase,
+            self.before.mean_ns / 1e6,
+            self.after.mean_ns / 1e6,
+            self.percent()
+        )?;
+        if let Some(sigma) = self.sigma() {
+            write!(f, ", {sigma:+.2}σ")?;
+        }
+        write!(f, ")")?;
+        if self.regressed {
+            write!(f, " REGRESSED")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(parse: f64, solve: f64, stddev: f64) -> Timing {
+        Timing {
+            parse: Phase {
+                mean_ns: parse,
+                stddev_ns: stddev,
+            },
+            solve: Phase {
+                mean_ns: solve,
+                stddev_ns: stddev,
+            },
+        }
+    }
+
+    #[test]
+    fn compare() -> Result<(), String> {
+        let mut before = Baseline::default();
+        before.insert(2024, 1, timing(1000.0, 2000.0, 0.0));
+        before.insert(2024, 2, timing(1000.0, 2000.0, 100.0));
+        before.insert(2024, 4, timing(1000.0, 2000.0, 10.0));
+        let before = Baseline::parse(&before.to_csv())?;
+
+        let mut after = Baseline::default();
+        after.insert(2024, 1, timing(1050.0, 3000.0, 0.0));
+        after.insert(2024, 2, timing(1150.0, 2500.0, 0.0));
+        after.insert(2024, 3, timing(1.0, 1.0, 0.0));
+        after.insert(2024, 4, timing(1050.0, 2010.0, 0.0));
+        after.insert(2025, 1, timing(9000.0, 9000.0, 0.0));
+
+        let tolerance = Tolerance {
+            percent: 10.0,
+            sigma: Some(3.0),
+        };
+        let regressed = before
+            .compare(&after, &tolerance)
+            .iter()
+            .map(|c| (c.num, c.phase, c.regressed))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            regressed,
+            vec![
+                (1, "parse", false),
+                (1, "solve", true),
+                (2, "parse", true),
+                (2, "solve", true),
+                (4, "parse", true),
+                (4, "solve", false),
+            ]
+        );
+        let change = &before.compare(&after, &tolerance)[4];
+        assert_eq!(
+            change.to_string(),
+            "2024 DAY 04 parse: 0.001ms -> 0.001ms (+5.0%, +5.00σ) REGRESSED"
+        );
+        assert!(Baseline::parse("1,1000,0,2000,0\n").is_err());
+        Ok(())
+    }
+}
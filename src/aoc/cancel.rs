@@ -0,0 +1,89 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::aoc::error::AocError;
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+/// A flag the runner sets to ask a solution to stop, e.g. when it has run out of time. Solutions
+/// can't be stopped from outside, so long-running loops should poll `check` and give up when it
+/// fails.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Run `f` with this as the current thread's token, so that `check` sees it being cancelled.
+    pub fn run<T>(&self, f: impl FnOnce() -> T) -> T {
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+        let result = f();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+        result
+    }
+}
+
+/// Whether the current thread's solution has been asked to stop. Always false outside
+/// `CancelToken::run`.
+pub fn cancelled() -> bool {
+    CURRENT.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .is_some_and(CancelToken::is_cancelled)
+    })
+}
+
+/// Fail with `AocError::Cancelled` if the current thread's solution has been asked to stop.
+///
+/// ```
+/// use aoc2024::aoc::cancel::{self, CancelToken};
+///
+/// let token = CancelToken::new();
+/// let steps = token.run(|| {
+///     let mut steps = 0;
+///     while cancel::check().is_ok() {
+///         steps += 1;
+///         if steps == 10 {
+///             token.cancel();
+///         }
+///     }
+///     steps
+/// });
+/// assert_eq!(steps, 10);
+/// ```
+pub fn check() -> Result<(), AocError> {
+    if cancelled() {
+        Err(AocError::Cancelled)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancel() {
+        let token = CancelToken::new();
+        token.cancel();
+        assert!(check().is_ok());
+        assert!(matches!(token.run(check), Err(AocError::Cancelled)));
+        assert!(CancelToken::new().run(check).is_ok());
+        assert!(!cancelled());
+    }
+}
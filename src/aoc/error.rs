@@ -0,0 +1,295 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+use crate::aoc::Input;
+
+/// Why a solution failed, either while parsing its input or while solving it.
+#[derive(Debug)]
+pub enum AocError {
+    /// The input could not be read. The file is filled in by the runner, as for `Parse`, when
+    /// the input is one.
+    Io {
+        file: Option<String>,
+        error: io::Error,
+    },
+    /// The input is malformed at the given line and column, both counting from 1. The file is
+    /// filled in by the runner, which knows where the input came from.
+    Parse {
+        file: Option<String>,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is well-formed but not a valid puzzle, e.g. a maze without a start.
+    InvalidInput(String),
+    /// The input is valid but the solution could not find an answer for it.
+    Unsolvable(String),
+    /// The solution gave up because the runner asked it to stop, e.g. when it ran out of time.
+    Cancelled,
+}
+
+impl AocError {
+    pub fn parse<S: Into<String>>(line: usize, column: usize, message: S) -> AocError {
+        AocError::Parse {
+            file: None,
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// A parse error at `token`, which must be a slice of `text`, the whole of the input.
+    ///
+    /// ```
+    /// use aoc2024::aoc::error::AocError;
+    ///
+    /// let text = "1 2\n3 x\n";
+    /// let token = text.split_whitespace().nth(3).unwrap();
+    /// assert_eq!(AocError::at(text, token, "bad").to_string(), "line 2, column 3: bad");
+    /// ```
+    pub fn at<S: Into<String>>(text: &str, token: &str, message: S) -> AocError {
+        let offset = offset(text, token).unwrap_or(text.len());
+        let before = &text[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+        AocError::parse(line, column, message)
+    }
+
+    pub fn invalid<S: Into<String>>(message: S) -> AocError {
+        AocError::InvalidInput(message.into())
+    }
+
+    pub fn unsolvable<S: Into<String>>(message: S) -> AocError {
+        AocError::Unsolvable(message.into())
+    }
+
+    /// Attach the input to an error from parsing it, so that parse errors name the file they were
+    /// found in. Errors from reading the input become `Io` errors, naming the file if there is
+    /// one.
+    pub fn from_input(error: Box<dyn Error>, input: &Input) -> Box<dyn Error> {
+        let error = match error.downcast::<io::Error>() {
+            Ok(error) => {
+                let file = matches!(input, Input::File(_)).then(|| input.to_string());
+                return Box::new(AocError::Io {
+                    file,
+                    error: *error,
+                });
+            }
+            Err(error) => error,
+        };
+        match error.downcast::<AocError>() {
+            Ok(error) => match *error {
+                AocError::Parse {
+                    file: None,
+                    line,
+                    column,
+                    message,
+                } => Box::new(AocError::Parse {
+                    file: Some(input.to_string()),
+                    line,
+                    column,
+                    message,
+                }),
+                error => Box::new(error),
+            },
+            Err(error) => error,
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Io { file, error } => match file {
+                Some(file) => write!(f, "could not read input {file}: {error}"),
+                None => write!(f, "could not read input: {error}"),
+            },
+            Self::Parse {
+                file,
+                line,
+                column,
+                message,
+            } => match file {
+                Some(file) => write!(f, "{file}:{line}:{column}: {message}"),
+                None => write!(f, "line {line}, column {column}: {message}"),
+            },
+            Self::InvalidInput(message) => write!(f, "invalid input: {message}"),
+            Self::Unsolvable(message) => write!(f, "no solution: {message}"),
+            Self::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(error: io::Error) -> AocError {
+        AocError::Io { file: None, error }
+    }
+}
+
+/// A numbered line of input, for reporting where in it parsing failed.
+///
+/// ```
+/// use aoc2024::aoc::error::Line;
+///
+/// let line = Line::new(0, "p=0,x");
+/// let (_, y) = line.text.split_once(',').unwrap();
+/// let error = line.parse::<i32>(y).unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "line 1, column 5: invalid value \"x\": invalid digit found in string"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub num: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// The line at a zero-based `index`, as counted by `enumerate`.
+    pub fn new(index: usize, text: &'a str) -> Line<'a> {
+        Line {
+            num: index + 1,
+            text,
+        }
+    }
+
+    /// A parse error at `token`, which should be a slice of the line. Other tokens are reported
+    /// at the end of the line.
+    pub fn error<S: Into<String>>(&self, token: &str, message: S) -> AocError {
+        let column = offset(self.text, token).unwrap_or(self.text.len()) + 1;
+        AocError::parse(self.num, column, message)
+    }
+
+    /// An invalid input error for a well-formed `token` on this line that the puzzle doesn't
+    /// allow, e.g. a coordinate outside the grid, naming where it is.
+    pub fn invalid<S: Display>(&self, token: &str, message: S) -> AocError {
+        let column = offset(self.text, token).unwrap_or(self.text.len()) + 1;
+        AocError::invalid(format!("line {}, column {column}: {message}", self.num))
+    }
+
+    /// A parse error at the end of the line, for something the line should have had.
+    pub fn missing(&self, what: &str) -> AocError {
+        AocError::parse(self.num, self.text.len() + 1, format!("missing {what}"))
+    }
+
+    /// Parse `token`, a slice of the line, reporting any failure at the token.
+    pub fn parse<T>(&self, token: &str) -> Result<T, AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        token
+            .parse()
+            .map_err(|e| self.error(token, format!("invalid value \"{token}\": {e}")))
+    }
+}
+
+/// Parse `token`, a slice of `text`, the whole of the input, reporting any failure at the token.
+pub fn parse_token<T>(text: &str, token: &str) -> Result<T, AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token
+        .parse()
+        .map_err(|e| AocError::at(text, token, format!("invalid value \"{token}\": {e}")))
+}
+
+/// The byte offset of `token` in `text`, if it is a slice of it.
+fn offset(text: &str, token: &str) -> Option<usize> {
+    let start = text.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize).checked_sub(start)?;
+    (offset + token.len() <= text.len()).then_some(offset)
+}
+
+/// Describe an error from running a solution on `input`. Parse errors quote the offending line
+/// of the input, with a marker under the column.
+///
+/// ```
+/// use aoc2024::aoc::error::{render, AocError};
+/// use aoc2024::aoc::Input;
+///
+/// let input = Input::text("3   4\n4   x\n");
+/// let error = AocError::from_input(AocError::parse(2, 5, "bad").into(), &input);
+/// assert_eq!(
+///     render(&*error, &input),
+///     "<memory>:2:5: bad\n  |\n2 | 4   x\n  |     ^"
+/// );
+/// ```
+pub fn render(error: &(dyn Error + 'static), input: &Input) -> String {
+    let Some(AocError::Parse { line, column, .. }) = error.downcast_ref::<AocError>() else {
+        return error.to_string();
+    };
+    let text = input
+        .open()
+        .ok()
+        .and_then(|reader| reader.lines().nth(line - 1))
+        .and_then(|text| text.ok());
+    let Some(text) = text else {
+        return error.to_string();
+    };
+    let num = line.to_string();
+    let gutter = " ".repeat(num.len());
+    let indent = " ".repeat(column.saturating_sub(1));
+    format!("{error}\n{gutter} |\n{num} | {text}\n{gutter} | {indent}^")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate() {
+        let text = "1 2\n3 x 5\n";
+        let error = AocError::at(text, &text[6..7], "bad");
+        assert_eq!(error.to_string(), "line 2, column 3: bad");
+        let error = AocError::at(text, "x", "bad");
+        assert_eq!(error.to_string(), "line 3, column 1: bad");
+
+        let line = Line::new(1, &text[4..9]);
+        let token = line.text.split(' ').nth(1).unwrap();
+        assert_eq!(
+            line.parse::<i32>(token).unwrap_err().to_string(),
+            "line 2, column 3: invalid value \"x\": invalid digit found in string"
+        );
+        assert_eq!(
+            line.missing("a fourth number").to_string(),
+            "line 2, column 6: missing a fourth number"
+        );
+        assert_eq!(
+            line.invalid(&line.text[4..], "too big").to_string(),
+            "invalid input: line 2, column 5: too big"
+        );
+    }
+
+    #[test]
+    fn render_parse_error() {
+        let input = Input::text("1 2\n3 x 5\n");
+        let error = AocError::from_input(Box::new(AocError::parse(2, 3, "bad")), &input);
+        assert_eq!(
+            render(&*error, &input),
+            "<memory>:2:3: bad\n  |\n2 | 3 x 5\n  |   ^"
+        );
+        let error = AocError::from_input(Box::new(io::Error::other("gone")), &input);
+        assert!(matches!(error.downcast_ref(), Some(AocError::Io { .. })));
+        assert_eq!(render(&*error, &input), "could not read input: gone");
+        let input = Input::file("missing.txt");
+        let error = AocError::from_input(Box::new(io::Error::other("gone")), &input);
+        assert_eq!(
+            render(&*error, &input),
+            "could not read input missing.txt: gone"
+        );
+    }
+}
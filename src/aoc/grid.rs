@@ -1,8 +1,26 @@
+use std::fmt::Display;
+use std::iter::Enumerate;
 use std::ops::{Index, IndexMut};
+use std::slice;
 
+use rustc_hash::FxHashMap;
+
+use crate::aoc::error::AocError;
 use crate::aoc::Position;
 
-#[derive(Debug)]
+/// A rectangular grid stored row by row. Cells are pushed in reading order and the dimensions set
+/// once the grid is filled, or the grid is parsed from text with `parse`.
+///
+/// ```
+/// use aoc2024::aoc::grid::Grid;
+///
+/// let mut grid = Grid::fill(0, 3, 2);
+/// grid[(2, 1)] = 7;
+/// assert_eq!(grid.get(&(2, 1)), Some(&7));
+/// assert_eq!(grid.get(&(3, 0)), None);
+/// assert_eq!(grid.len(), 6);
+/// ```
+#[derive(Clone, Debug)]
 pub struct Grid<T>
 where
     T: Clone,
@@ -12,7 +30,6 @@ where
     pub height: usize,
 }
 
-#[allow(dead_code)]
 impl<T: Clone> Grid<T> {
     pub fn new() -> Grid<T> {
         Grid {
@@ -22,6 +39,81 @@ impl<T: Clone> Grid<T> {
         }
     }
 
+    /// Parse a grid with a row per line, mapping each character to a cell. Rows must all be the
+    /// same length, and a character the mapping rejects is reported at its line and column.
+    ///
+    /// ```
+    /// use aoc2024::aoc::grid::Grid;
+    ///
+    /// let digit = |c: char| c.to_digit(10).ok_or("not a digit");
+    /// let grid = Grid::parse("012\n345\n", digit)?;
+    /// assert_eq!((grid.width, grid.height, grid[(2, 1)]), (3, 2, 5));
+    ///
+    /// let error = Grid::parse("012\n3x5\n", digit).unwrap_err();
+    /// assert_eq!(error.to_string(), "line 2, column 2: not a digit");
+    /// # Ok::<(), aoc2024::aoc::error::AocError>(())
+    /// ```
+    pub fn parse<F, E>(text: &str, cell: F) -> Result<Grid<T>, AocError>
+    where
+        F: FnMut(char) -> Result<T, E>,
+        E: Display,
+    {
+        Grid::parse_with_markers(text, "", cell).map(|(grid, _)| grid)
+    }
+
+    /// Parse a grid like `parse`, also collecting the positions of each of the `markers`
+    /// characters, e.g. the start and end of a maze. The mapping still decides which cell a
+    /// marker stands on.
+    ///
+    /// ```
+    /// use aoc2024::aoc::grid::Grid;
+    ///
+    /// let wall = |c: char| match c {
+    ///     '#' => Ok(true),
+    ///     '.' | 'S' | 'E' => Ok(false),
+    ///     _ => Err(format!("invalid character '{c}'")),
+    /// };
+    /// let (grid, markers) = Grid::parse_with_markers("#S.\n.E#\n", "SE", wall)?;
+    /// assert_eq!(markers.one('S')?, (1, 0));
+    /// assert_eq!(markers.one('E')?, (1, 1));
+    /// assert!(!grid[(1, 1)]);
+    /// # Ok::<(), aoc2024::aoc::error::AocError>(())
+    /// ```
+    pub fn parse_with_markers<F, E>(
+        text: &str,
+        markers: &str,
+        mut cell: F,
+    ) -> Result<(Grid<T>, Markers), AocError>
+    where
+        F: FnMut(char) -> Result<T, E>,
+        E: Display,
+    {
+        let mut grid = Grid::new();
+        let mut found = Markers::default();
+        for (y, line) in text.lines().enumerate() {
+            let mut width = 0;
+            for (x, c) in line.chars().enumerate() {
+                let item = cell(c).map_err(|e| AocError::parse(y + 1, x + 1, e.to_string()))?;
+                grid.push(item);
+                if markers.contains(c) {
+                    found.0.entry(c).or_default().push((x, y));
+                }
+                width += 1;
+            }
+            if y == 0 {
+                grid.width = width;
+            } else if width != grid.width {
+                let message = format!("expected a row of {} cells, found {width}", grid.width);
+                return Err(AocError::parse(y + 1, width.min(grid.width) + 1, message));
+            }
+            grid.height += 1;
+        }
+        if grid.is_empty() {
+            return Err(AocError::invalid("empty grid"));
+        }
+        Ok((grid, found))
+    }
+
     pub fn fill(value: T, width: usize, height: usize) -> Grid<T> {
         Grid {
             items: vec![value; width * height],
@@ -30,6 +122,22 @@ impl<T: Clone> Grid<T> {
         }
     }
 
+    /// A grid with each cell computed from its position.
+    pub fn from_fn<F>(width: usize, height: usize, mut cell: F) -> Grid<T>
+    where
+        F: FnMut(Position) -> T,
+    {
+        let mut grid = Grid::new();
+        grid.extend(
+            (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .map(&mut cell),
+        );
+        grid.width = width;
+        grid.height = height;
+        grid
+    }
+
     pub fn clear(&mut self) {
         self.items.clear();
     }
@@ -62,8 +170,287 @@ impl<T: Clone> Grid<T> {
         self.items.len()
     }
 
-    pub fn iter(&self) -> GridIterator<T> {
-        GridIterator::new(&self)
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The number of cells within the grid's dimensions, which may be fewer than have been pushed
+    /// while it is being filled.
+    fn size(&self) -> usize {
+        (self.width * self.height).min(self.items.len())
+    }
+
+    /// Each cell with its position, row by row.
+    ///
+    /// ```
+    /// use aoc2024::aoc::grid::Grid;
+    ///
+    /// let mut grid = Grid::new();
+    /// grid.extend("ab\ncd".lines().flat_map(str::chars));
+    /// (grid.width, grid.height) = (2, 2);
+    /// let cells: Vec<_> = grid.iter().collect();
+    /// assert_eq!(cells[0], ((0, 0), &'a'));
+    /// assert_eq!(cells[3], ((1, 1), &'d'));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            items: self.items[..self.size()].iter().enumerate(),
+            width: self.width,
+        }
+    }
+
+    /// Each cell with its position, row by row, for updating in place.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let size = self.size();
+        IterMut {
+            items: self.items[..size].iter_mut().enumerate(),
+            width: self.width,
+        }
+    }
+
+    /// Each row as a slice, from the top.
+    ///
+    /// ```
+    /// use aoc2024::aoc::grid::Grid;
+    ///
+    /// let grid = Grid::from_fn(2, 2, |(x, y)| x + 2 * y);
+    /// assert_eq!(grid.rows().collect::<Vec<_>>(), [[0, 1], [2, 3]]);
+    /// let columns = grid.columns().map(|c| c.copied().collect::<Vec<_>>());
+    /// assert_eq!(columns.collect::<Vec<_>>(), [[0, 2], [1, 3]]);
+    /// assert_eq!(grid.positions().last(), Some((1, 1)));
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.items[..self.size()].chunks(self.width.max(1))
+    }
+
+    /// Each column, from the left, as an iterator over its cells from the top.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let items = &self.items[..self.size()];
+        (0..self.width).map(move |x| items[x.min(items.len())..].iter().step_by(self.width))
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// The cells matching `predicate`, with their positions, row by row.
+    ///
+    /// ```
+    /// use aoc2024::aoc::grid::Grid;
+    ///
+    /// let mut grid = Grid::new();
+    /// grid.extend("#..#.#".chars());
+    /// (grid.width, grid.height) = (3, 2);
+    /// let walls: Vec<_> = grid.enumerate_where(|&c| c == '#').map(|(pos, _)| pos).collect();
+    /// assert_eq!(walls, [(0, 0), (0, 1), (2, 1)]);
+    /// ```
+    pub fn enumerate_where<P>(&self, mut predicate: P) -> impl Iterator<Item = (Position, &T)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().filter(move |(_, item)| predicate(item))
+    }
+
+    /// The orthogonal neighbours of a cell (up, down, left and right) that are within the grid,
+    /// with their positions.
+    ///
+    /// ```
+    /// use aoc2024::aoc::grid::Grid;
+    ///
+    /// let grid = Grid::parse("abc\ndef\n", |c| Ok::<_, String>(c))?;
+    /// let cells: Vec<_> = grid.neighbors((0, 0)).map(|(_, &c)| c).collect();
+    /// assert_eq!(cells, ['d', 'b']);
+    /// assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+    /// # Ok::<(), aoc2024::aoc::error::AocError>(())
+    /// ```
+    pub fn neighbors(&self, pos: Position) -> impl Iterator<Item = (Position, &T)> {
+        self.neighbors_with(pos, ORTHOGONAL, Edges::Bounded)
+    }
+
+    /// The orthogonal and diagonal neighbours of a cell that are within the grid, with their
+    /// positions.
+    pub fn neighbors8(&self, pos: Position) -> impl Iterator<Item = (Position, &T)> {
+        self.neighbors_with(pos, ADJACENT, Edges::Bounded)
+    }
+
+    /// The cells at each of the `offsets` from a cell, with their positions, in the order of the
+    /// offsets. `edges` decides what happens to offsets that lead off the grid.
+    ///
+    /// ```
+    /// use aoc2024::aoc::grid::{Edges, Grid};
+    ///
+    /// let grid = Grid::parse("abc\ndef\n", |c| Ok::<_, String>(c))?;
+    /// let knight = [(2, 1), (-2, 1)];
+    /// let cells = |edges| grid.neighbors_with((0, 0), &knight, edges).map(|(_, &c)| c).collect::<Vec<_>>();
+    /// assert_eq!(cells(Edges::Bounded), ['f']);
+    /// assert_eq!(cells(Edges::Wrap), ['f', 'e']);
+    /// assert_eq!(cells(Edges::Clamp), ['f', 'd']);
+    /// # Ok::<(), aoc2024::aoc::error::AocError>(())
+    /// ```
+    pub fn neighbors_with<'a>(
+        &'a self,
+        pos: Position,
+        offsets: &'a [Offset],
+        edges: Edges,
+    ) -> impl Iterator<Item = (Position, &'a T)> {
+        offsets.iter().filter_map(move |&offset| {
+            let pos = edges.step(pos, offset, self.width, self.height)?;
+            Some((pos, self.get(&pos)?))
+        })
+    }
+
+    /// A grid of the same size with each cell mapped by `f`.
+    ///
+    /// ```
+    /// use aoc2024::aoc::grid::Grid;
+    ///
+    /// let grid = Grid::from_fn(2, 2, |(x, y)| x + 2 * y);
+    /// assert_eq!(grid.map(|&n| n * 10)[(1, 1)], 30);
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        U: Clone,
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            items: self.items[..self.size()].iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// A grid `N` times as wide, with each cell replaced by the `N` cells `f` maps it to, left to
+    /// right.
+    ///
+    /// ```
+    /// use aoc2024::aoc::grid::Grid;
+    ///
+    /// let grid = Grid::parse("#O.\n", |c| Ok::<_, String>(c))?;
+    /// let wide = grid.scale_x(|&c| match c {
+    ///     'O' => ['[', ']'],
+    ///     c => [c, c],
+    /// });
+    /// assert_eq!(wide.rows().next(), Some(&['#', '#', '[', ']', '.', '.'][..]));
+    /// # Ok::<(), aoc2024::aoc::error::AocError>(())
+    /// ```
+    pub fn scale_x<U, F, const N: usize>(&self, mut f: F) -> Grid<U>
+    where
+        U: Clone,
+        F: FnMut(&T) -> [U; N],
+    {
+        Grid {
+            items: self.items[..self.size()].iter().flat_map(&mut f).collect(),
+            width: N * self.width,
+            height: self.height,
+        }
+    }
+
+    /// The grid reflected in its main diagonal, so that rows become columns.
+    ///
+    /// ```
+    /// use aoc2024::aoc::grid::Grid;
+    ///
+    /// let grid = Grid::parse("abc\ndef\n", |c| Ok::<_, String>(c))?;
+    /// let transposed = grid.transpose();
+    /// assert_eq!(transposed.rows().collect::<Vec<_>>(), [['a', 'd'], ['b', 'e'], ['c', 'f']]);
+    /// assert_eq!(grid.flip_horizontal().rows().next(), Some(&['c', 'b', 'a'][..]));
+    /// assert_eq!(grid.flip_vertical().rows().next(), Some(&['d', 'e', 'f'][..]));
+    /// # Ok::<(), aoc2024::aoc::error::AocError>(())
+    /// ```
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// The grid turned a quarter turn clockwise.
+    ///
+    /// ```
+    /// use aoc2024::aoc::grid::Grid;
+    ///
+    /// let grid = Grid::parse("ab\ncd\nef\n", |c| Ok::<_, String>(c))?;
+    /// let rotated = grid.rotate_right();
+    /// assert_eq!(rotated.rows().collect::<Vec<_>>(), [['e', 'c', 'a'], ['f', 'd', 'b']]);
+    /// assert_eq!(rotated.rotate_left().rows().collect::<Vec<_>>(), [['a', 'b'], ['c', 'd'], ['e', 'f']]);
+    /// # Ok::<(), aoc2024::aoc::error::AocError>(())
+    /// ```
+    pub fn rotate_right(&self) -> Grid<T> {
+        let height = self.height;
+        Grid::from_fn(height, self.width, |(x, y)| {
+            self[(y, height - 1 - x)].clone()
+        })
+    }
+
+    /// The grid turned a quarter turn anticlockwise.
+    pub fn rotate_left(&self) -> Grid<T> {
+        let width = self.width;
+        Grid::from_fn(self.height, width, |(x, y)| {
+            self[(width - 1 - y, x)].clone()
+        })
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let width = self.width;
+        Grid::from_fn(width, self.height, |(x, y)| {
+            self[(width - 1 - x, y)].clone()
+        })
+    }
+
+    /// The grid mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let height = self.height;
+        Grid::from_fn(self.width, height, |(x, y)| {
+            self[(x, height - 1 - y)].clone()
+        })
+    }
+
+    /// The `width` by `height` region with its top-left corner at `origin`, or `None` if it does
+    /// not fit within the grid.
+    ///
+    /// ```
+    /// use aoc2024::aoc::grid::Grid;
+    ///
+    /// let grid = Grid::from_fn(3, 3, |(x, y)| x + 3 * y);
+    /// let corner = grid.subgrid((1, 1), 2, 2).unwrap();
+    /// assert_eq!(corner.rows().collect::<Vec<_>>(), [[4, 5], [7, 8]]);
+    /// assert!(grid.subgrid((2, 2), 2, 2).is_none());
+    /// ```
+    pub fn subgrid(&self, origin: Position, width: usize, height: usize) -> Option<Grid<T>> {
+        let (left, top) = origin;
+        if left + width > self.width || top + height > self.height {
+            return None;
+        }
+        Some(Grid::from_fn(width, height, |(x, y)| {
+            self[(left + x, top + y)].clone()
+        }))
+    }
+
+    /// The grid surrounded by a border `size` cells thick, filled with `value`.
+    ///
+    /// ```
+    /// use aoc2024::aoc::grid::Grid;
+    ///
+    /// let padded = Grid::fill(1, 1, 1).pad(1, 0);
+    /// assert_eq!(padded.rows().collect::<Vec<_>>(), [[0, 0, 0], [0, 1, 0], [0, 0, 0]]);
+    /// ```
+    pub fn pad(&self, size: usize, value: T) -> Grid<T> {
+        let (width, height) = (self.width + 2 * size, self.height + 2 * size);
+        Grid::from_fn(width, height, |(x, y)| {
+            let inside =
+                (size..size + self.width).contains(&x) && (size..size + self.height).contains(&y);
+            if inside {
+                self[(x - size, y - size)].clone()
+            } else {
+                value.clone()
+            }
+        })
+    }
+}
+
+impl<T: Clone> Default for Grid<T> {
+    fn default() -> Self {
+        Grid::new()
     }
 }
 
@@ -78,7 +465,10 @@ impl<T: Clone> Index<Position> for Grid<T> {
     fn index(&self, index: Position) -> &Self::Output {
         let (x, y) = index;
         if x >= self.width || y >= self.height {
-            panic!();
+            panic!(
+                "{index:?} out of bounds for {}x{} grid",
+                self.width, self.height
+            );
         }
         self.items.index(y * self.width + x)
     }
@@ -88,36 +478,356 @@ impl<T: Clone> IndexMut<Position> for Grid<T> {
     fn index_mut(&mut self, index: Position) -> &mut Self::Output {
         let (x, y) = index;
         if x >= self.width || y >= self.height {
-            panic!();
+            panic!(
+                "{index:?} out of bounds for {}x{} grid",
+                self.width, self.height
+            );
         }
         self.items.index_mut(y * self.width + x)
     }
 }
 
-pub struct GridIterator<'a, T>
-where
-    T: Clone,
-{
-    grid: &'a Grid<T>,
-    i: usize,
+/// A step from one cell to another, as a change in `x` and `y`.
+pub type Offset = (isize, isize);
+
+/// The offsets to the orthogonal neighbours of a cell: up, down, left and right, in the order of
+/// `Direction::all`.
+pub const ORTHOGONAL: &[Offset] = &[(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+/// The offsets to the diagonal neighbours of a cell, row by row.
+pub const DIAGONAL: &[Offset] = &[(-1, -1), (1, -1), (-1, 1), (1, 1)];
+
+/// The offsets to all eight neighbours of a cell, row by row.
+pub const ADJACENT: &[Offset] = &[
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// What happens to a step that leads off the edge of a grid.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Edges {
+    /// Steps off the grid go nowhere.
+    Bounded,
+    /// The grid wraps around like a torus, so stepping off one edge comes back on the opposite
+    /// one.
+    Wrap,
+    /// Steps stop at the edge, so a cell on the edge may be its own neighbour.
+    Clamp,
 }
 
-impl<'a, T: Clone> GridIterator<'a, T> {
-    pub fn new(grid: &'a Grid<T>) -> GridIterator<'a, T> {
-        GridIterator { grid, i: 0 }
+impl Edges {
+    /// Where a step by `offset` from `pos` leads on a `width` by `height` grid, if anywhere.
+    ///
+    /// ```
+    /// use aoc2024::aoc::grid::Edges;
+    ///
+    /// assert_eq!(Edges::Bounded.step((0, 1), (-1, 0), 4, 3), None);
+    /// assert_eq!(Edges::Wrap.step((0, 1), (-1, 0), 4, 3), Some((3, 1)));
+    /// assert_eq!(Edges::Clamp.step((0, 1), (-1, 0), 4, 3), Some((0, 1)));
+    /// ```
+    pub fn step(
+        self,
+        pos: Position,
+        offset: Offset,
+        width: usize,
+        height: usize,
+    ) -> Option<Position> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let axis = |from: usize, by: isize, size: usize| match self {
+            Edges::Bounded => from.checked_add_signed(by).filter(|&to| to < size),
+            Edges::Wrap => Some((from as isize + by).rem_euclid(size as isize) as usize),
+            Edges::Clamp => Some((from as isize + by).clamp(0, size as isize - 1) as usize),
+        };
+        Some((
+            axis(pos.0, offset.0, width)?,
+            axis(pos.1, offset.1, height)?,
+        ))
     }
 }
 
-impl<'a, T: Clone> Iterator for GridIterator<'a, T> {
-    type Item = (Position, T);
+/// The positions of the marker characters found while parsing a grid, row by row.
+///
+/// ```
+/// use aoc2024::aoc::grid::Grid;
+///
+/// let open = |c: char| Ok::<_, String>(c != '#');
+/// let (_, markers) = Grid::parse_with_markers("0.0\n#9#\n", "09S", open)?;
+/// assert_eq!(markers.all('0'), [(0, 0), (2, 0)]);
+/// assert_eq!(markers.one('9')?, (1, 1));
+/// assert!(markers.all('S').is_empty());
+/// assert!(markers.one('0').is_err());
+/// # Ok::<(), aoc2024::aoc::error::AocError>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct Markers(FxHashMap<char, Vec<Position>>);
+
+impl Markers {
+    /// Every position of the marker, which is empty if there are none.
+    pub fn all(&self, marker: char) -> &[Position] {
+        self.0.get(&marker).map_or(&[], Vec::as_slice)
+    }
+
+    /// The position of a marker that should appear exactly once.
+    pub fn one(&self, marker: char) -> Result<Position, AocError> {
+        match self.all(marker) {
+            [pos] => Ok(*pos),
+            found => Err(AocError::invalid(format!(
+                "expected one '{marker}' in the grid, found {}",
+                found.len()
+            ))),
+        }
+    }
+}
+
+/// An iterator over a grid's cells and their positions, row by row.
+pub struct Iter<'a, T> {
+    items: Enumerate<slice::Iter<'a, T>>,
+    width: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (Position, &'a T);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.i >= self.grid.len() {
-            return None;
+        let (i, item) = self.items.next()?;
+        Some(((i % self.width, i / self.width), item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// An iterator over mutable references to a grid's cells and their positions, row by row.
+pub struct IterMut<'a, T> {
+    items: Enumerate<slice::IterMut<'a, T>>,
+    width: usize,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = (Position, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, item) = self.items.next()?;
+        Some(((i % self.width, i / self.width), item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        let mut grid = Grid::new();
+        grid.extend(0..6);
+        grid.width = 3;
+        grid.height = 2;
+        grid
+    }
+
+    #[test]
+    fn iter() {
+        let grid = grid();
+        let cells: Vec<_> = grid.iter().collect();
+        assert_eq!(
+            cells,
+            [
+                ((0, 0), &0),
+                ((1, 0), &1),
+                ((2, 0), &2),
+                ((0, 1), &3),
+                ((1, 1), &4),
+                ((2, 1), &5)
+            ]
+        );
+        assert!(cells.iter().all(|&(pos, &item)| grid[pos] == item));
+        assert_eq!(grid.iter().len(), 6);
+        assert_eq!(
+            grid.positions().collect::<Vec<_>>(),
+            cells.iter().map(|c| c.0).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut grid = grid();
+        for ((x, y), item) in grid.iter_mut() {
+            *item = (10 * y + x) as u8;
         }
-        self.i += 1;
-        let x = self.i % self.grid.width;
-        let y = self.i / self.grid.width;
-        self.grid.items.get(self.i).cloned().map(|i| ((x, y), i))
+        assert_eq!(grid[(2, 1)], 12);
+        assert_eq!(grid[(1, 0)], 1);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[0, 1, 2], [3, 4, 5]]);
+        let columns: Vec<Vec<_>> = grid.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, [[0, 3], [1, 4], [2, 5]]);
+        let odd: Vec<_> = grid.enumerate_where(|n| n % 2 == 1).collect();
+        assert_eq!(odd, [((1, 0), &1), ((0, 1), &3), ((2, 1), &5)]);
+    }
+
+    #[test]
+    fn parse() -> Result<(), AocError> {
+        let cell = |c: char| match c {
+            '#' | '.' | '^' => Ok(c),
+            _ => Err(format!("invalid character '{c}'")),
+        };
+        let (grid, markers) = Grid::parse_with_markers("#.^\n^.#\n...\n", "^#", cell)?;
+        assert_eq!((grid.width, grid.height), (3, 3));
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['#', '.', '^'], ['^', '.', '#'], ['.'; 3]]
+        );
+        assert_eq!(markers.all('^'), [(2, 0), (0, 1)]);
+        assert_eq!(
+            markers.one('#').unwrap_err().to_string(),
+            "invalid input: expected one '#' in the grid, found 2"
+        );
+        assert!(markers.all('S').is_empty());
+
+        let error = |text| Grid::parse(text, cell).unwrap_err().to_string();
+        assert_eq!(error("#.\n.x\n"), "line 2, column 2: invalid character 'x'");
+        assert_eq!(
+            error("#.\n.\n"),
+            "line 2, column 2: expected a row of 2 cells, found 1"
+        );
+        assert_eq!(
+            error("#.\n...\n"),
+            "line 2, column 3: expected a row of 2 cells, found 3"
+        );
+        assert_eq!(error(""), "invalid input: empty grid");
+        Ok(())
+    }
+
+    #[test]
+    fn transforms() {
+        // 0 1 2
+        // 3 4 5
+        let grid = grid();
+        let rows = |grid: &Grid<u8>| grid.rows().map(<[u8]>::to_vec).collect::<Vec<_>>();
+        assert_eq!(rows(&grid.transpose()), [[0, 3], [1, 4], [2, 5]]);
+        assert_eq!(rows(&grid.rotate_right()), [[3, 0], [4, 1], [5, 2]]);
+        assert_eq!(rows(&grid.rotate_left()), [[2, 5], [1, 4], [0, 3]]);
+        assert_eq!(rows(&grid.flip_horizontal()), [[2, 1, 0], [5, 4, 3]]);
+        assert_eq!(rows(&grid.flip_vertical()), [[3, 4, 5], [0, 1, 2]]);
+        let turned = grid
+            .rotate_right()
+            .rotate_right()
+            .rotate_right()
+            .rotate_right();
+        assert_eq!(rows(&turned), rows(&grid));
+
+        assert_eq!(rows(&grid.subgrid((1, 0), 2, 2).unwrap()), [[1, 2], [4, 5]]);
+        assert_eq!(rows(&grid.subgrid((2, 1), 1, 1).unwrap()), [[5]]);
+        assert!(grid.subgrid((2, 0), 2, 1).is_none());
+        assert!(grid.subgrid((0, 1), 1, 2).is_none());
+
+        let padded = grid.pad(1, 9);
+        assert_eq!((padded.width, padded.height), (5, 4));
+        assert_eq!(
+            rows(&padded),
+            [
+                vec![9; 5],
+                vec![9, 0, 1, 2, 9],
+                vec![9, 3, 4, 5, 9],
+                vec![9; 5]
+            ]
+        );
+
+        assert_eq!(rows(&grid.map(|n| n * 2)), [[0, 2, 4], [6, 8, 10]]);
+        let wide = grid.scale_x(|&n| [n, n + 10]);
+        assert_eq!((wide.width, wide.height), (6, 2));
+        assert_eq!(rows(&wide), [[0, 10, 1, 11, 2, 12], [3, 13, 4, 14, 5, 15]]);
+        assert_eq!(
+            Grid::from_fn(2, 2, |(x, y)| x + 2 * y)
+                .iter()
+                .map(|(_, &n)| n)
+                .collect::<Vec<_>>(),
+            [0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn neighbors() {
+        // 0 1 2
+        // 3 4 5
+        let grid = grid();
+        let cells = |iter: &mut dyn Iterator<Item = (Position, &u8)>| {
+            iter.map(|(_, &n)| n).collect::<Vec<_>>()
+        };
+        assert_eq!(cells(&mut grid.neighbors((1, 0))), [4, 0, 2]);
+        assert_eq!(cells(&mut grid.neighbors((2, 1))), [2, 4]);
+        assert_eq!(cells(&mut grid.neighbors8((1, 1))), [0, 1, 2, 3, 5]);
+        assert_eq!(
+            cells(&mut grid.neighbors_with((0, 0), DIAGONAL, Edges::Bounded)),
+            [4]
+        );
+        assert_eq!(grid.neighbors((1, 0)).next(), Some(((1, 1), &4)));
+
+        assert_eq!(
+            cells(&mut grid.neighbors_with((0, 0), ORTHOGONAL, Edges::Wrap)),
+            [3, 3, 2, 1]
+        );
+        assert_eq!(
+            cells(&mut grid.neighbors_with((0, 0), DIAGONAL, Edges::Wrap)),
+            [5, 4, 5, 4]
+        );
+        assert_eq!(
+            cells(&mut grid.neighbors_with((0, 0), ORTHOGONAL, Edges::Clamp)),
+            [0, 3, 0, 1]
+        );
+        assert_eq!(
+            cells(&mut grid.neighbors_with((2, 1), &[(5, 5)], Edges::Clamp)),
+            [5]
+        );
+        assert_eq!(
+            Grid::<u8>::new()
+                .neighbors_with((0, 0), ADJACENT, Edges::Wrap)
+                .count(),
+            0
+        );
+
+        assert_eq!(Edges::Bounded.step((0, 0), (-1, 0), 3, 2), None);
+        assert_eq!(Edges::Wrap.step((0, 0), (-4, -3), 3, 2), Some((2, 1)));
+        assert_eq!(Edges::Clamp.step((0, 0), (-4, 3), 3, 2), Some((0, 1)));
+    }
+
+    #[test]
+    fn unfinished() {
+        // Cells pushed before the dimensions are set, or beyond them, are not visited.
+        let mut grid = grid();
+        grid.push(6);
+        assert_eq!(grid.iter().count(), 6);
+        assert_eq!(grid.rows().count(), 2);
+        grid.width = 0;
+        assert_eq!(grid.iter().count(), 0);
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid.positions().count(), 0);
+        assert_eq!(Grid::<u8>::new().iter().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "(3, 1) out of bounds for 3x2 grid")]
+    fn index_out_of_bounds() {
+        let _ = grid()[(3, 1)];
     }
 }
@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::read_to_string;
+use std::path::Path;
+
+use crate::aoc::answer::Answer;
+use crate::aoc::Answers;
+
+/// Known answers keyed by year, day and part, loaded from a small subset of TOML:
+///
+/// ```toml
+/// [2024.day01]
+/// part1 = 1603498
+/// part2 = "some,text"
+///
+/// [2024.day08]
+/// part2 = """
+/// #..#
+/// ####
+/// """
+/// ```
+///
+/// ```
+/// use aoc2024::aoc::manifest::Manifest;
+/// use aoc2024::aoc::Answers;
+///
+/// let manifest = Manifest::parse("[2024.day01]\npart1 = 11\npart2 = 31\n")?;
+/// let [part1, part2] = manifest.check(2024, 1, &Answers::both(11, 30));
+/// assert!(!part1.failed() && part2.failed());
+/// # Ok::<(), String>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct Manifest {
+    answers: BTreeMap<(usize, usize, usize), Answer>,
+}
+
+impl Manifest {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Manifest, Box<dyn Error>> {
+        let path = path.as_ref();
+        let content =
+            read_to_string(path).map_err(|e| format!("could not read {}: {e}", path.display()))?;
+        Manifest::parse(&content).map_err(|e| format!("{}: {e}", path.display()).into())
+    }
+
+    pub fn parse(content: &str) -> Result<Manifest, String> {
+        let mut manifest = Manifest::default();
+        let mut day = None;
+        let mut lines = content.lines().enumerate();
+        while let Some((i, line)) = lines.next() {
+            let line_num = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[') {
+                let key = header
+                    .strip_suffix(']')
+                    .and_then(|h| h.trim().split_once('.'))
+                    .and_then(|(year, day)| {
+                        let num = day.strip_prefix("day")?.parse::<usize>().ok()?;
+                        Some((year.parse::<usize>().ok()?, num))
+                    })
+                    .ok_or(format!(
+                        "line {line_num}: expected a header like [2024.day01]"
+                    ))?;
+                day = Some(key);
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(format!("line {line_num}: expected key = value"))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                key => return Err(format!("line {line_num}: unknown key \"{key}\"")),
+            };
+            let (year, num) = day.ok_or(format!(
+                "line {line_num}: answer outside of a [YYYY.dayNN] table"
+            ))?;
+            let value = match value.trim().strip_prefix("\"\"\"") {
+                Some(first) => parse_multiline(first, &mut lines, line_num)?,
+                None => parse_value(value.trim(), line_num)?,
+            };
+            manifest
+                .answers
+                .insert((year, num, part), Answer::parse(&value));
+        }
+        Ok(manifest)
+    }
+
+    pub fn get(&self, year: usize, num: usize, part: usize) -> Option<&Answer> {
+        self.answers.get(&(year, num, part))
+    }
+
+    /// Compare a day's answers against the manifest, returning a verdict for each part.
+    pub fn check(&self, year: usize, num: usize, answers: &Answers) -> [Verdict; 2] {
+        [1, 2].map(
+            |part| match (self.get(year, num, part), answers.part(part)) {
+                (None, _) => Verdict::Unknown,
+                (Some(expected), Some(actual)) if expected == actual => Verdict::Pass,
+                (Some(expected), _) => Verdict::Fail(expected.clone()),
+            },
+        )
+    }
+}
+
+/// Collect the lines of a `"""` string up to the closing delimiter, skipping the newline right
+/// after the opening one as TOML does.
+fn parse_multiline<'a>(
+    first: &str,
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+    line_num: usize,
+) -> Result<String, String> {
+    if let Some(value) = first.strip_suffix("\"\"\"") {
+        return Ok(value.to_string());
+    }
+    let mut value = first.to_string();
+    for (_, line) in lines.by_ref() {
+        if let Some(last) = line.strip_suffix("\"\"\"") {
+            value.push_str(last);
+            return Ok(value);
+        }
+        value.push_str(line);
+        value.push('\n');
+    }
+    Err(format!("line {line_num}: unterminated string"))
+}
+
+/// Parse a single-line value, which is either a quoted string or a bare word.
+pub fn parse_value(raw: &str, line_num: usize) -> Result<String, String> {
+    if let Some(rest) = raw.strip_prefix('"') {
+        return rest
+            .strip_suffix('"')
+            .map(|s| s.to_string())
+            .ok_or(format!("line {line_num}: unterminated string"));
+    }
+    let value = raw.split('#').next().unwrap_or("").trim();
+    if value.is_empty() || value.contains(char::is_whitespace) {
+        return Err(format!("line {line_num}: invalid value \"{raw}\""));
+    }
+    Ok(value.to_string())
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum Verdict {
+    Pass,
+    Fail(Answer),
+    Unknown,
+}
+
+impl Verdict {
+    pub fn failed(&self) -> bool {
+        matches!(self, Self::Fail(_))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "PASS"),
+            Self::Fail(expected) => write!(f, "FAIL (expected {expected})"),
+            Self::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn check() -> Result<(), String> {
+        let manifest = Manifest::parse(
+            "# comment\n[2024.day01]\npart1 = 11\npart2 = \"a,b\"\n\n[2024.day02]\npart1 = 2 # trailing\n",
+        )?;
+        assert_eq!(
+            manifest.check(2024, 1, &Answers::both(11, "a,b")),
+            [Verdict::Pass, Verdict::Pass]
+        );
+        assert_eq!(
+            manifest.check(2024, 2, &Answers::both(3, 4)),
+            [Verdict::Fail(Answer::from(2)), Verdict::Unknown]
+        );
+        assert_eq!(
+            manifest.check(2024, 3, &Answers::both(1, 2)),
+            [Verdict::Unknown, Verdict::Unknown]
+        );
+        assert_eq!(
+            manifest.check(2025, 1, &Answers::both(11, "a,b")),
+            [Verdict::Unknown, Verdict::Unknown]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn multiline() -> Result<(), String> {
+        let manifest = Manifest::parse(
+            "[2024.day08]\npart1 = \"\"\"7\"\"\"\npart2 = \"\"\"\n#..#\n####\n\"\"\"\n",
+        )?;
+        assert_eq!(manifest.get(2024, 8, 1), Some(&Answer::from(7)));
+        assert_eq!(
+            manifest.get(2024, 8, 2),
+            Some(&Answer::lines(["#..#", "####"]))
+        );
+        assert!(Manifest::parse("[2024.day08]\npart1 = \"\"\"\n#..#\n").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Manifest::parse("part1 = 1").is_err());
+        assert!(Manifest::parse("[2024.day01]\npart3 = 1").is_err());
+        assert!(Manifest::parse("[2024.day01]\npart1 = \"open").is_err());
+        assert!(Manifest::parse("[2024.dayone]").is_err());
+        assert!(Manifest::parse("[day01]").is_err());
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::aoc::Solution;
+
+/// A puzzle and the solution to it, as listed in the registry in `years.rs`.
+#[derive(Debug)]
+pub struct Puzzle {
+    pub year: usize,
+    pub day: usize,
+    pub title: &'static str,
+    pub tags: &'static [Tag],
+    pub new: fn() -> Box<dyn Solution>,
+}
+
+impl Puzzle {
+    /// A fresh solution, ready to parse its input.
+    pub fn solution(&self) -> Box<dyn Solution> {
+        (self.new)()
+    }
+
+    /// Whether the puzzle has every one of the given tags.
+    pub fn has_tags(&self, tags: &[Tag]) -> bool {
+        tags.iter().all(|tag| self.tags.contains(tag))
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} DAY {:0>2}: {}", self.year, self.day, self.title)?;
+        if !self.tags.is_empty() {
+            let tags = self.tags.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+            write!(f, " [{}]", tags.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// The kind of problem a puzzle poses, for finding related puzzles.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Tag {
+    Grid,
+    Graph,
+    Simulation,
+    NumberTheory,
+}
+
+impl Tag {
+    pub const ALL: [Tag; 4] = [Tag::Grid, Tag::Graph, Tag::Simulation, Tag::NumberTheory];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Grid => "grid",
+            Self::Graph => "graph",
+            Self::Simulation => "simulation",
+            Self::NumberTheory => "number-theory",
+        }
+    }
+}
+
+impl Display for Tag {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Tag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Tag, String> {
+        Tag::ALL
+            .into_iter()
+            .find(|tag| tag.name() == s)
+            .ok_or_else(|| {
+                let names = Tag::ALL.map(|t| t.name()).join(", ");
+                format!("unknown tag \"{s}\" (expected one of {names})")
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn tags() {
+        for tag in Tag::ALL {
+            assert_eq!(tag.to_string().parse::<Tag>(), Ok(tag));
+        }
+        assert!("maze".parse::<Tag>().is_err());
+    }
+}
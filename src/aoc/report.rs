@@ -0,0 +1,183 @@
+use crate::aoc::answer::Answer;
+use crate::aoc::stats::Statistics;
+use crate::aoc::SolutionData;
+
+/// Header for the per-day rows produced by `csv_row`.
+pub const CSV_HEADER: &str =
+    "year,day,part1,part2,part1_complete,part2_complete,parse_ns,solve_ns,\
+                              part1_ns,part2_ns,error";
+
+/// Header for the statistics row produced by `csv_statistics`.
+pub const CSV_STATISTICS_HEADER: &str = "elapsed_ns,wall_ns,completed,failed,mean_ns,stddev_ns";
+
+/// A day's row, for a day from `year`.
+pub fn csv_row(year: usize, datum: &SolutionData) -> String {
+    format!(
+        "{},{},{},{},{},{},{},{},{},{},{}",
+        year,
+        datum.num,
+        csv_field(&answer_text(datum, 1).unwrap_or_default()),
+        csv_field(&answer_text(datum, 2).unwrap_or_default()),
+        datum.answers.part(1).is_some(),
+        datum.answers.part(2).is_some(),
+        datum.parse_time.as_nanos(),
+        datum.solve_time().as_nanos(),
+        datum.part1_time.as_nanos(),
+        datum.part2_time.as_nanos(),
+        csv_field(datum.error.as_deref().unwrap_or_default())
+    )
+}
+
+pub fn csv_statistics(stats: &Statistics) -> String {
+    format!(
+        "{},{},{},{},{},{}",
+        stats.elapsed_time.as_nanos(),
+        stats.wall_time.as_nanos(),
+        stats.solved.len(),
+        stats.failed.len(),
+        mean_nanos(stats).unwrap_or_default(),
+        stddev_nanos(stats).unwrap_or_default()
+    )
+}
+
+/// A JSON document holding the answers and timings of each day from `year` along with the
+/// statistics summary. Integer answers are numbers and other answers are strings.
+pub fn json(year: usize, data: &[SolutionData], stats: &Statistics) -> String {
+    let days = data
+        .iter()
+        .map(|datum| {
+            format!(
+                "{{\"year\":{},\"day\":{},\"part1\":{},\"part2\":{},\"part1_complete\":{},\"part2_complete\":{},\"parse_ns\":{},\"solve_ns\":{},\"part1_ns\":{},\"part2_ns\":{},\"error\":{}}}",
+                year,
+                datum.num,
+                json_answer(datum.answers.part(1)),
+                json_answer(datum.answers.part(2)),
+                datum.answers.part(1).is_some(),
+                datum.answers.part(2).is_some(),
+                datum.parse_time.as_nanos(),
+                datum.solve_time().as_nanos(),
+                datum.part1_time.as_nanos(),
+                datum.part2_time.as_nanos(),
+                json_option(datum.error.clone())
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    format!(
+        "{{\"days\":[{days}],\"statistics\":{{\"elapsed_ns\":{},\"wall_ns\":{},\"completed\":{},\"failed\":{},\"mean_ns\":{},\"stddev_ns\":{}}}}}",
+        stats.elapsed_time.as_nanos(),
+        stats.wall_time.as_nanos(),
+        stats.solved.len(),
+        stats.failed.len(),
+        json_number(mean_nanos(stats)),
+        json_number(stddev_nanos(stats))
+    )
+}
+
+fn mean_nanos(stats: &Statistics) -> Option<u128> {
+    stats.total.as_ref().map(|s| (s.mean * 1e6).round() as u128)
+}
+
+fn stddev_nanos(stats: &Statistics) -> Option<u128> {
+    stats
+        .total
+        .as_ref()
+        .map(|s| (s.sample_stddev() * 1e6).round() as u128)
+}
+
+fn json_number(value: Option<u128>) -> String {
+    value.map_or("null".to_string(), |v| v.to_string())
+}
+
+fn answer_text(datum: &SolutionData, part: usize) -> Option<String> {
+    datum.answers.part(part).map(|answer| answer.to_string())
+}
+
+fn json_answer(answer: Option<&Answer>) -> String {
+    match answer {
+        None => "null".to_string(),
+        Some(answer @ (Answer::Int(_) | Answer::BigInt(_))) => answer.to_string(),
+        Some(answer) => json_string(&answer.to_string()),
+    }
+}
+
+fn json_option(value: Option<String>) -> String {
+    value.map_or("null".to_string(), |v| json_string(&v))
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::Answers;
+    use std::time::Duration;
+
+    #[test]
+    fn formats() {
+        let data = vec![SolutionData::new(
+            17,
+            Answers::both("4,6,3", 117440),
+            Duration::from_micros(15),
+            Duration::from_micros(10),
+            Duration::from_micros(15),
+        )];
+        let stats = Statistics::calc(&data, None, Duration::from_micros(50));
+        assert_eq!(
+            csv_row(2024, &data[0]),
+            "2024,17,\"4,6,3\",117440,true,true,15000,25000,10000,15000,"
+        );
+        assert_eq!(
+            json(2024, &data, &stats),
+            "{\"days\":[{\"year\":2024,\"day\":17,\"part1\":\"4,6,3\",\"part2\":117440,\
+             \"part1_complete\":true,\"part2_complete\":true,\"parse_ns\":15000,\"solve_ns\":25000,\"part1_ns\":10000,\"part2_ns\":15000,\"error\":null}],\"statistics\":{\"elapsed_ns\":40000,\
+             \"wall_ns\":50000,\"completed\":1,\"failed\":0,\"mean_ns\":40000,\"stddev_ns\":0}}"
+        );
+        assert_eq!(json_string("a\"b\\\n"), "\"a\\\"b\\\\\\n\"");
+
+        let part1 = SolutionData::new(
+            5,
+            Answers::part1(143),
+            Duration::ZERO,
+            Duration::ZERO,
+            Duration::ZERO,
+        );
+        assert!(csv_row(2024, &part1).starts_with("2024,5,143,,true,false,"));
+        assert!(json(2024, &[part1], &stats).contains(
+            "\"part1\":143,\"part2\":null,\"part1_complete\":true,\"part2_complete\":false"
+        ));
+
+        let failed = SolutionData::failed(5, "invalid input: no rules");
+        assert_eq!(
+            csv_row(2024, &failed),
+            "2024,5,,,false,false,0,0,0,0,invalid input: no rules"
+        );
+        assert!(json(2024, &[failed], &stats).contains(
+            "\"part1\":null,\"part2\":null,\"part1_complete\":false,\"part2_complete\":false,\"parse_ns\":0,\"solve_ns\":0,\
+             \"part1_ns\":0,\"part2_ns\":0,\"error\":\"invalid input: no rules\"}"
+        ));
+    }
+}
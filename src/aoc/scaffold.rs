@@ -0,0 +1,269 @@
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::aoc::puzzle::Tag;
+
+/// A new day to generate, with the module skeleton registered in its year's module and the year
+/// registered in `years.rs` if it is new, along with an empty file for its first example.
+#[derive(Debug)]
+pub struct Scaffold {
+    pub year: usize,
+    pub day: usize,
+    pub title: String,
+    pub tags: Vec<Tag>,
+}
+
+impl Scaffold {
+    /// Write the new module into the crate at `root`, returning the files that were created or
+    /// changed. Nothing is written if the day already exists.
+    pub fn create<P: AsRef<Path>>(&self, root: P) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+        if !(1..=25).contains(&self.day) {
+            return Err(format!("invalid day {} (expected 1 to 25)", self.day).into());
+        }
+        let src = root.as_ref().join("src");
+        let years = src.join("years.rs");
+        let year = src.join("years").join(format!("y{}.rs", self.year));
+        let module = src
+            .join("years")
+            .join(format!("y{}", self.year))
+            .join(format!("day{:0>2}.rs", self.day));
+        if module.exists() {
+            return Err(format!("{} already exists", module.display()).into());
+        }
+        let read = |path: &Path| {
+            fs::read_to_string(path).map_err(|e| format!("could not read {}: {e}", path.display()))
+        };
+
+        let mut changes = Vec::new();
+        let year_source = if year.exists() {
+            read(&year)?
+        } else {
+            let source = register(&read(&years)?, "y", self.year, "PUZZLES")?;
+            changes.push((years, source));
+            year_module(self.year)
+        };
+        let year_source = register(&year_source, "day", self.day, "PUZZLE")?;
+        changes.push((year, year_source));
+        changes.push((module, self.module()));
+        let example = root.as_ref().join(self.example());
+        if !example.exists() {
+            changes.push((example, String::new()));
+        }
+
+        let mut written = Vec::new();
+        for (path, source) in changes {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)
+                    .map_err(|e| format!("could not create {}: {e}", dir.display()))?;
+            }
+            fs::write(&path, source)
+                .map_err(|e| format!("could not write {}: {e}", path.display()))?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+
+    /// The path of the day's first example, relative to the crate.
+    fn example(&self) -> String {
+        format!("test_cases/{}/day{:0>2}_01.txt", self.year, self.day)
+    }
+
+    /// The source of the day's module: a solution that reads its input as lines, and an example
+    /// to fill in from the puzzle text.
+    pub fn module(&self) -> String {
+        let name = format!("Day{:0>2}", self.day);
+        let (tag_import, tags) = if self.tags.is_empty() {
+            ("Puzzle", String::new())
+        } else {
+            let tags = self.tags.iter().map(|t| format!("Tag::{t:?}"));
+            ("{Puzzle, Tag}", tags.collect::<Vec<_>>().join(", "))
+        };
+        format!(
+            r#"use crate::aoc::answer::Answer;
+use crate::aoc::puzzle::{tag_import};
+use crate::aoc::{{read_lines, Answers, Example, Input, Solution}};
+use std::error::Error;
+
+pub const PUZZLE: Puzzle = Puzzle {{
+    year: {year},
+    day: {day},
+    title: {title:?},
+    tags: &[{tags}],
+    new: || Box::new({name}::new()),
+}};
+
+#[derive(Debug)]
+struct {name} {{
+    lines: Vec<String>,
+}}
+
+impl {name} {{
+    pub fn new() -> Self {{
+        {name} {{ lines: Vec::new() }}
+    }}
+}}
+
+impl Solution for {name} {{
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {{
+        for line in read_lines(input)? {{
+            self.lines.push(line?);
+        }}
+        Ok(())
+    }}
+
+    fn examples(&self) -> Vec<Example> {{
+        vec![Example::new(
+            "{example}",
+            Answers::both(0, 0),
+        )]
+    }}
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {{
+        Err(format!("part 1 is not solved yet ({{}} lines)", self.lines.len()).into())
+    }}
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {{
+        Err(format!("part 2 is not solved yet ({{}} lines)", self.lines.len()).into())
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use crate::aoc::check_examples;
+
+    #[test]
+    #[ignore = "needs the example from the puzzle text"]
+    fn examples() -> Result<(), Box<dyn Error>> {{
+        check_examples(&PUZZLE)
+    }}
+}}
+"#,
+            year = self.year,
+            day = self.day,
+            title = self.title,
+            example = self.example(),
+        )
+    }
+}
+
+/// The source of a year's module before any days are registered in it.
+fn year_module(year: usize) -> String {
+    format!(
+        "use crate::aoc::puzzle::Puzzle;\n\n\
+         /// The puzzles from {year} with a solution, in order of day.\n\
+         pub static PUZZLES: &[Puzzle] = &[\n];\n"
+    )
+}
+
+/// Register a module named `prefix` and a zero-padded `num` in a registry's source: declare it
+/// alongside the other `pub mod` lines and add its `item` to the list of them, keeping both in
+/// order.
+fn register(source: &str, prefix: &str, num: usize, item: &str) -> Result<String, String> {
+    let name = if prefix == "day" {
+        format!("{prefix}{num:0>2}")
+    } else {
+        format!("{prefix}{num}")
+    };
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    let declaration = format!("pub mod {name};");
+    if lines.contains(&declaration) {
+        return Err(format!("{name} is already registered"));
+    }
+    let modules = lines
+        .iter()
+        .take_while(|l| l.starts_with("pub mod "))
+        .count();
+    let at = lines[..modules]
+        .iter()
+        .position(|l| *l > declaration)
+        .unwrap_or(modules);
+    lines.insert(at, declaration);
+    if modules == 0 {
+        lines.insert(at + 1, String::new());
+    }
+
+    // The list runs from the `static` line to the one ending in `];`.
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with("pub static ") || l.starts_with("static "))
+        .ok_or("no registry list found")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|l| l.trim_end().ends_with("];"))
+            .ok_or("unterminated registry list")?;
+    let list = lines[start..=end].join("\n");
+    let (head, rest) = list.split_once("= &[").ok_or("no registry list found")?;
+    let body = rest.trim_end().strip_suffix("];").unwrap_or(rest);
+    let mut items: Vec<String> = body
+        .split(',')
+        .map(|i| i.trim().to_string())
+        .filter(|i| !i.is_empty())
+        .collect();
+    items.push(format!("{name}::{item}"));
+    items.sort();
+    // Lay the list out as rustfmt would, on one line only while it is short.
+    let line = format!("{head}= &[{}];", items.join(", "));
+    let list = if items.join(", ").len() <= 60 && line.len() <= 100 {
+        line
+    } else {
+        let items = items.iter().map(|i| format!("    {i},\n"));
+        format!("{head}= &[\n{}];", items.collect::<String>())
+    };
+    lines.splice(start..=end, list.lines().map(String::from));
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_day() -> Result<(), String> {
+        let source = "pub mod day01;\npub mod day02;\npub mod day04;\npub mod day05;\n\n\
+                      use crate::aoc::puzzle::Puzzle;\n\npub static PUZZLES: &[Puzzle] = \
+                      &[day01::PUZZLE, day02::PUZZLE, day04::PUZZLE, day05::PUZZLE];\n";
+        assert_eq!(
+            register(source, "day", 3, "PUZZLE")?,
+            "pub mod day01;\npub mod day02;\npub mod day03;\npub mod day04;\npub mod day05;\n\n\
+             use crate::aoc::puzzle::Puzzle;\n\npub static PUZZLES: &[Puzzle] = &[\n    \
+             day01::PUZZLE,\n    day02::PUZZLE,\n    day03::PUZZLE,\n    day04::PUZZLE,\n    \
+             day05::PUZZLE,\n];\n"
+        );
+        assert!(register(source, "day", 4, "PUZZLE").is_err());
+
+        let first = register(&year_module(2025), "day", 1, "PUZZLE")?;
+        assert!(first.starts_with("pub mod day01;\n\nuse crate::aoc::puzzle::Puzzle;\n"));
+        assert!(first.ends_with("pub static PUZZLES: &[Puzzle] = &[day01::PUZZLE];\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn register_year() -> Result<(), String> {
+        let source = "pub mod y2024;\n\nuse crate::aoc::puzzle::Puzzle;\n\n\
+                      static YEARS: &[&[Puzzle]] = &[y2024::PUZZLES];\n\nfn rest() {}\n";
+        assert_eq!(
+            register(source, "y", 2025, "PUZZLES")?,
+            "pub mod y2024;\npub mod y2025;\n\nuse crate::aoc::puzzle::Puzzle;\n\n\
+             static YEARS: &[&[Puzzle]] = &[y2024::PUZZLES, y2025::PUZZLES];\n\nfn rest() {}\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn module() {
+        let scaffold = Scaffold {
+            year: 2025,
+            day: 7,
+            title: "Laboratories".to_string(),
+            tags: vec![Tag::Grid, Tag::NumberTheory],
+        };
+        let module = scaffold.module();
+        assert!(module.contains("use crate::aoc::puzzle::{Puzzle, Tag};\n"));
+        assert!(module.contains("    day: 7,\n    title: \"Laboratories\",\n"));
+        assert!(module.contains("tags: &[Tag::Grid, Tag::NumberTheory],"));
+        assert!(module.contains("struct Day07 {"));
+    }
+}
@@ -0,0 +1,305 @@
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use crate::aoc::SolutionData;
+
+/// Summary statistics for a set of samples, e.g. the timings from repeated runs of a solution.
+#[derive(Clone, Debug)]
+pub struct Summary {
+    sorted: Vec<f64>,
+    pub mean: f64,
+    /// The population variance, treating the samples as the whole data set.
+    pub variance: f64,
+    /// The sample variance, with Bessel's correction. Zero when there is a single sample.
+    pub sample_variance: f64,
+}
+
+impl Summary {
+    /// Summarize the samples, returning `None` if there are none.
+    ///
+    /// ```
+    /// use aoc2024::aoc::stats::Summary;
+    ///
+    /// let summary = Summary::new(&[3.0, 1.0, 2.0]).unwrap();
+    /// assert_eq!((summary.min(), summary.median(), summary.max()), (1.0, 2.0, 3.0));
+    /// assert!(Summary::new(&[]).is_none());
+    /// ```
+    pub fn new(samples: &[f64]) -> Option<Summary> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let n = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / n;
+        let squares = sorted.iter().map(|x| (x - mean).powi(2)).sum::<f64>();
+        let sample_variance = if sorted.len() > 1 {
+            squares / (n - 1.0)
+        } else {
+            0.0
+        };
+        Some(Summary {
+            sorted,
+            mean,
+            variance: squares / n,
+            sample_variance,
+        })
+    }
+
+    /// Summarize durations in milliseconds.
+    pub fn from_durations(durations: &[Duration]) -> Option<Summary> {
+        let millis: Vec<f64> = durations.iter().map(|d| d.as_secs_f64() * 1000.0).collect();
+        Summary::new(&millis)
+    }
+
+    pub fn count(&self) -> usize {
+        self.sorted.len()
+    }
+
+    pub fn min(&self) -> f64 {
+        self.sorted[0]
+    }
+
+    pub fn max(&self) -> f64 {
+        self.sorted[self.sorted.len() - 1]
+    }
+
+    pub fn median(&self) -> f64 {
+        self.percentile(50.0)
+    }
+
+    /// The `p`th percentile, interpolating linearly between the closest ranks. `p` is clamped to
+    /// the range 0 to 100.
+    pub fn percentile(&self, p: f64) -> f64 {
+        let rank = p.clamp(0.0, 100.0) / 100.0 * (self.sorted.len() - 1) as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+        self.sorted[lower] + (self.sorted[upper] - self.sorted[lower]) * (rank - lower as f64)
+    }
+
+    /// The population standard deviation.
+    pub fn stddev(&self) -> f64 {
+        self.variance.sqrt()
+    }
+
+    /// The sample standard deviation, appropriate when the samples are repeated measurements.
+    pub fn sample_stddev(&self) -> f64 {
+        self.sample_variance.sqrt()
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:.3}ms, median {:.3}ms, mean {:.3}ms, p95 {:.3}ms, sample σ {:.3}ms",
+            self.min(),
+            self.median(),
+            self.mean,
+            self.percentile(95.0),
+            self.sample_stddev()
+        )
+    }
+}
+
+/// Statistics for a run of several days. Only days that answered every requested part are
+/// summarized, and the summaries are `None` when there are none. Days that failed are listed
+/// separately.
+pub struct Statistics<'a> {
+    /// The elapsed time of each day's solution, summed. This is not CPU time: when days run
+    /// concurrently it includes time spent waiting for a core.
+    pub elapsed_time: Duration,
+    /// The elapsed time for the whole run, which is shorter when days run concurrently.
+    pub wall_time: Duration,
+    pub solved: Vec<&'a SolutionData>,
+    pub failed: Vec<&'a SolutionData>,
+    /// Combined parse and solve times in milliseconds.
+    pub total: Option<Summary>,
+    pub parse: Option<Summary>,
+    pub solve: Option<Summary>,
+    pub part1: Option<Summary>,
+    pub part2: Option<Summary>,
+}
+
+impl<'a> Statistics<'a> {
+    /// Summarize the days in `data`, which were asked for the given part, or both if `None`.
+    pub fn calc(
+        data: &'a [SolutionData],
+        part: Option<usize>,
+        wall_time: Duration,
+    ) -> Statistics<'a> {
+        let elapsed_time = data.iter().map(|s| s.parse_time + s.solve_time()).sum();
+        let parts: Vec<usize> = (1..=2)
+            .filter(|&p| part.is_none_or(|part| part == p))
+            .collect();
+        let solved: Vec<_> = data
+            .iter()
+            .filter(|s| !s.is_failed() && parts.iter().all(|&p| s.answers.part(p).is_some()))
+            .collect();
+        let times = |time: fn(&SolutionData) -> Duration| {
+            Summary::from_durations(&solved.iter().map(|&s| time(s)).collect::<Vec<_>>())
+        };
+        let part_times = |p: usize, time: fn(&SolutionData) -> Duration| {
+            parts.contains(&p).then(|| times(time)).flatten()
+        };
+        Statistics {
+            elapsed_time,
+            wall_time,
+            total: times(|s| s.parse_time + s.solve_time()),
+            parse: times(|s| s.parse_time),
+            solve: times(|s| s.solve_time()),
+            part1: part_times(1, |s| s.part1_time),
+            part2: part_times(2, |s| s.part2_time),
+            solved,
+            failed: data.iter().filter(|s| s.is_failed()).collect(),
+        }
+    }
+
+    /// The `n` slowest solved days, slowest first.
+    pub fn slowest(&self, n: usize) -> Vec<&'a SolutionData> {
+        let mut slowest = self.solved.clone();
+        slowest.sort_by_key(|s| std::cmp::Reverse(s.parse_time + s.solve_time()));
+        slowest.truncate(n);
+        slowest
+    }
+}
+
+impl Display for Statistics<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "~- STATISTICS -~")?;
+        write!(
+            f,
+            "T = {}ms (wall {}ms), n = {}",
+            self.elapsed_time.as_millis(),
+            self.wall_time.as_millis(),
+            self.solved.len()
+        )?;
+        if let Some(total) = &self.total {
+            write!(
+                f,
+                ", μ = {:.2}ms, sample σ = {:.2}ms",
+                total.mean,
+                total.sample_stddev()
+            )?;
+        }
+        if !self.failed.is_empty() {
+            let days = self.failed.iter().map(|s| s.num.to_string());
+            write!(f, "\nFailed: {}", days.collect::<Vec<_>>().join(", "))?;
+        }
+        if let Some(parse) = &self.parse {
+            write!(f, "\nParse: {parse}")?;
+        }
+        if let Some(solve) = &self.solve {
+            write!(f, "\nSolve: {solve}")?;
+        }
+        if let Some(part1) = &self.part1 {
+            write!(f, "\nPart 1: {part1}")?;
+        }
+        if let Some(part2) = &self.part2 {
+            write!(f, "\nPart 2: {part2}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::Answers;
+
+    #[test]
+    fn summary() {
+        let summary = Summary::new(&[4.0, 1.0, 3.0, 2.0, 5.0]).unwrap();
+        assert_eq!(summary.count(), 5);
+        assert_eq!(summary.min(), 1.0);
+        assert_eq!(summary.max(), 5.0);
+        assert_eq!(summary.median(), 3.0);
+        assert_eq!(summary.mean, 3.0);
+        assert_eq!(summary.variance, 2.0);
+        assert_eq!(summary.sample_variance, 2.5);
+        assert!((summary.percentile(95.0) - 4.8).abs() < 1e-9);
+        assert_eq!(summary.percentile(0.0), 1.0);
+        assert_eq!(summary.percentile(150.0), 5.0);
+        assert!((summary.stddev() - 2.0f64.sqrt()).abs() < 1e-9);
+        assert!((summary.sample_stddev() - 2.5f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn summary_edge_cases() {
+        assert!(Summary::new(&[]).is_none());
+        let single = Summary::new(&[7.0]).unwrap();
+        assert_eq!(single.median(), 7.0);
+        assert_eq!(single.percentile(95.0), 7.0);
+        assert_eq!(single.stddev(), 0.0);
+        assert_eq!(single.sample_stddev(), 0.0);
+        assert_eq!(Summary::new(&[1.0, 2.0]).unwrap().median(), 1.5);
+    }
+
+    #[test]
+    fn statistics() {
+        let ms = Duration::from_millis;
+        let data = vec![
+            SolutionData::new(1, Answers::both(1, 2), ms(1), ms(1), ms(2)),
+            SolutionData::new(2, Answers::part1(1), ms(100), ms(100), ms(0)),
+            SolutionData::new(3, Answers::both(1, 2), ms(2), ms(6), ms(4)),
+            SolutionData::failed(4, "invalid input: no robot"),
+        ];
+        let stats = Statistics::calc(&data, None, ms(50));
+        assert_eq!(stats.elapsed_time, ms(216));
+        assert_eq!(stats.solved.len(), 2);
+        assert_eq!(
+            stats.failed.iter().map(|s| s.num).collect::<Vec<_>>(),
+            vec![4]
+        );
+        let total = stats.total.as_ref().unwrap();
+        assert_eq!(total.mean, 8.0);
+        assert_eq!(total.stddev(), 4.0);
+        assert_eq!(stats.parse.as_ref().unwrap().mean, 1.5);
+        assert_eq!(stats.solve.as_ref().unwrap().max(), 10.0);
+        assert_eq!(stats.part1.as_ref().unwrap().mean, 3.5);
+        assert_eq!(stats.part2.as_ref().unwrap().mean, 3.0);
+        let slowest = stats.slowest(5).iter().map(|s| s.num).collect::<Vec<_>>();
+        assert_eq!(slowest, vec![3, 1]);
+        assert!(stats.to_string().contains("\nFailed: 4\n"));
+        assert!(stats.to_string().contains("μ = 8.00ms, sample σ = 5.66ms"));
+    }
+
+    #[test]
+    fn statistics_without_complete_days() {
+        let data = vec![SolutionData::new(
+            1,
+            Answers::None,
+            Duration::from_millis(1),
+            Duration::from_millis(1),
+            Duration::ZERO,
+        )];
+        let stats = Statistics::calc(&data, None, Duration::from_millis(2));
+        assert!(stats.total.is_none() && stats.parse.is_none() && stats.solve.is_none());
+        assert!(stats.slowest(5).is_empty());
+        assert_eq!(
+            stats.to_string(),
+            "~- STATISTICS -~\nT = 2ms (wall 2ms), n = 0"
+        );
+        assert!(Statistics::calc(&[], None, Duration::ZERO).total.is_none());
+    }
+
+    #[test]
+    fn statistics_for_one_part() {
+        let ms = Duration::from_millis;
+        let data = vec![
+            SolutionData::new(1, Answers::part1(1), ms(1), ms(3), ms(0)),
+            SolutionData::new(2, Answers::part1(1), ms(2), ms(6), ms(0)),
+            SolutionData::new(3, Answers::None, ms(1), ms(0), ms(0)),
+            SolutionData::failed(4, "invalid input: no robot"),
+        ];
+        let stats = Statistics::calc(&data, Some(1), ms(20));
+        assert_eq!(stats.solved.len(), 2);
+        assert_eq!(stats.total.as_ref().unwrap().mean, 6.0);
+        assert_eq!(stats.part1.as_ref().unwrap().mean, 4.5);
+        assert!(stats.part2.is_none());
+        let slowest = stats.slowest(5).iter().map(|s| s.num).collect::<Vec<_>>();
+        assert_eq!(slowest, vec![2, 1]);
+        assert!(stats.to_string().contains(", n = 2, μ = 6.00ms"));
+        assert!(Statistics::calc(&data, Some(2), ms(20)).solved.is_empty());
+    }
+}
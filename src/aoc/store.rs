@@ -0,0 +1,290 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::aoc::manifest::parse_value;
+use crate::aoc::puzzle::Puzzle;
+use crate::aoc::Input;
+
+/// The directory inputs are read from when nothing else is configured.
+const DEFAULT_ROOT: &str = "./data";
+const ROOT_VAR: &str = "AOC_DATA_DIR";
+const USER_VAR: &str = "AOC_USER";
+const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// Settings read from the config file, a small subset of TOML:
+///
+/// ```toml
+/// data_dir = "/home/me/aoc/inputs"
+/// user = "work"
+/// ```
+///
+/// A relative `data_dir` is relative to the directory holding the config file.
+#[derive(Debug, Default, PartialEq)]
+pub struct Config {
+    pub data_dir: Option<PathBuf>,
+    pub user: Option<String>,
+}
+
+impl Config {
+    /// The config file named by `AOC_CONFIG`, or `aoc/config.toml` in the user's config directory.
+    pub fn path() -> Option<PathBuf> {
+        if let Some(path) = env::var_os(CONFIG_VAR) {
+            return Some(PathBuf::from(path));
+        }
+        env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+            .map(|dir| dir.join("aoc").join("config.toml"))
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Config, Box<dyn Error>> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+        let mut config = Config::parse(&content).map_err(|e| format!("{}: {e}", path.display()))?;
+        if let (Some(data_dir), Some(parent)) = (&config.data_dir, path.parent()) {
+            config.data_dir = Some(parent.join(data_dir));
+        }
+        Ok(config)
+    }
+
+    pub fn parse(content: &str) -> Result<Config, String> {
+        let mut config = Config::default();
+        for (i, line) in content.lines().enumerate() {
+            let line_num = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(format!("line {line_num}: expected key = value"))?;
+            let value = parse_value(value.trim(), line_num)?;
+            match key.trim() {
+                "data_dir" => config.data_dir = Some(PathBuf::from(value)),
+                "user" => config.user = Some(value),
+                key => return Err(format!("line {line_num}: unknown key \"{key}\"")),
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// Where puzzle inputs are kept, laid out by year as `<root>/2024/day06.txt`. Each user's inputs
+/// live in a separate set under `<root>/users/<user>/`, since every account gets different inputs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InputStore {
+    root: PathBuf,
+    user: Option<String>,
+}
+
+impl InputStore {
+    pub fn new<P: AsRef<Path>>(root: P, user: Option<&str>) -> Result<InputStore, String> {
+        let store = InputStore {
+            root: root.as_ref().to_path_buf(),
+            user: None,
+        };
+        match user {
+            Some(user) => store.with_user(user),
+            None => Ok(store),
+        }
+    }
+
+    /// The store configured by the `AOC_DATA_DIR` and `AOC_USER` environment variables, falling
+    /// back to the config file and then to `./data`.
+    pub fn from_env() -> Result<InputStore, Box<dyn Error>> {
+        let config = match Config::path() {
+            Some(path) if path.exists() => Config::load(path)?,
+            Some(path) if env::var_os(CONFIG_VAR).is_some() => {
+                return Err(format!("config file {} does not exist", path.display()).into())
+            }
+            _ => Config::default(),
+        };
+        let var = |name| env::var(name).ok().filter(|v| !v.is_empty());
+        let root = var(ROOT_VAR)
+            .map(PathBuf::from)
+            .or(config.data_dir)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_ROOT));
+        let user = var(USER_VAR).or(config.user);
+        Ok(InputStore::new(root, user.as_deref())?)
+    }
+
+    /// The same store, reading the given user's set of inputs.
+    pub fn with_user(self, user: &str) -> Result<InputStore, String> {
+        let valid = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+        if user.is_empty() || !user.chars().all(valid) {
+            return Err(format!(
+                "invalid user \"{user}\" (use letters, digits, '-' and '_')"
+            ));
+        }
+        Ok(InputStore {
+            user: Some(user.to_string()),
+            ..self
+        })
+    }
+
+    /// The directory holding the inputs for a year.
+    pub fn dir(&self, year: usize) -> PathBuf {
+        match &self.user {
+            Some(user) => self.root.join("users").join(user).join(year.to_string()),
+            None => self.root.join(year.to_string()),
+        }
+    }
+
+    /// Where the input for a day is kept.
+    ///
+    /// ```
+    /// use aoc2024::aoc::store::InputStore;
+    /// use std::path::Path;
+    ///
+    /// let store = InputStore::new("inputs", Some("work"))?;
+    /// assert_eq!(store.path(2024, 6), Path::new("inputs/users/work/2024/day06.txt"));
+    /// # Ok::<(), String>(())
+    /// ```
+    pub fn path(&self, year: usize, day: usize) -> PathBuf {
+        self.dir(year).join(format!("day{day:0>2}.txt"))
+    }
+
+    /// The input for a day, or an error naming the file if it is missing.
+    pub fn input(&self, year: usize, day: usize) -> Result<Input, String> {
+        let path = self.path(year, day);
+        if !path.is_file() {
+            return Err(format!(
+                "no input for {year} day {day}: {} does not exist",
+                path.display()
+            ));
+        }
+        Ok(Input::File(path))
+    }
+
+    /// Copy a puzzle's input into the store after checking it. An input already in the store is
+    /// never replaced by a different one. Returns whether the input was new.
+    pub fn import<P: AsRef<Path>>(&self, puzzle: &Puzzle, from: P) -> Result<bool, String> {
+        let from = from.as_ref();
+        check(puzzle, from)?;
+        let to = self.path(puzzle.year, puzzle.day);
+        let content =
+            fs::read(from).map_err(|e| format!("could not read {}: {e}", from.display()))?;
+        if to.exists() {
+            let existing =
+                fs::read(&to).map_err(|e| format!("could not read {}: {e}", to.display()))?;
+            if existing != content {
+                return Err(format!(
+                    "{} differs from the input already in {}",
+                    from.display(),
+                    to.display()
+                ));
+            }
+            return Ok(false);
+        }
+        let dir = self.dir(puzzle.year);
+        fs::create_dir_all(&dir).map_err(|e| format!("could not create {}: {e}", dir.display()))?;
+        fs::write(&to, content).map_err(|e| format!("could not write {}: {e}", to.display()))?;
+        Ok(true)
+    }
+}
+
+/// Find a day's input in a directory of downloaded inputs, named like `day06.txt`, `day6.txt`,
+/// `06.txt` or `6.txt`.
+pub fn find_input<P: AsRef<Path>>(dir: P, day: usize) -> Option<PathBuf> {
+    [
+        format!("day{day:0>2}.txt"),
+        format!("day{day}.txt"),
+        format!("{day:0>2}.txt"),
+        format!("{day}.txt"),
+    ]
+    .into_iter()
+    .map(|name| dir.as_ref().join(name))
+    .find(|path| path.is_file())
+}
+
+/// Check that a file looks like an input for the puzzle: non-empty text with Unix line endings,
+/// which the puzzle's solution can parse.
+pub fn check<P: AsRef<Path>>(puzzle: &Puzzle, path: P) -> Result<(), String> {
+    let path = path.as_ref();
+    let content = fs::read(path).map_err(|e| format!("could not read {}: {e}", path.display()))?;
+    let problem = if content.iter().all(u8::is_ascii_whitespace) {
+        Some("is empty".to_string())
+    } else if std::str::from_utf8(&content).is_err() {
+        Some("is not text".to_string())
+    } else if content.contains(&b'\r') {
+        Some("has Windows line endings".to_string())
+    } else {
+        puzzle
+            .solution()
+            .parse_input(&Input::bytes(content))
+            .err()
+            .map(|e| format!("could not be parsed: {e}"))
+    };
+    match problem {
+        Some(problem) => Err(format!("{} {problem}", path.display())),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::years;
+
+    #[test]
+    fn config() -> Result<(), String> {
+        let config = Config::parse("# inputs\ndata_dir = \"/srv/aoc\"\nuser = work\n")?;
+        assert_eq!(
+            config,
+            Config {
+                data_dir: Some(PathBuf::from("/srv/aoc")),
+                user: Some("work".to_string()),
+            }
+        );
+        assert!(Config::parse("root = \"/srv/aoc\"").is_err());
+        assert!(Config::parse("user").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn layout() -> Result<(), String> {
+        let store = InputStore::new("/srv/aoc", None)?;
+        assert_eq!(store.path(2024, 6), Path::new("/srv/aoc/2024/day06.txt"));
+        let store = store.with_user("work")?;
+        assert_eq!(
+            store.path(2024, 6),
+            Path::new("/srv/aoc/users/work/2024/day06.txt")
+        );
+        assert!(InputStore::new("/srv/aoc", Some("../work")).is_err());
+        assert!(InputStore::new("/srv/aoc", Some("")).is_err());
+        let missing = store.input(2024, 6).unwrap_err();
+        assert!(missing.contains("/srv/aoc/users/work/2024/day06.txt"));
+        Ok(())
+    }
+
+    #[test]
+    fn import() -> Result<(), String> {
+        let dir = env::temp_dir().join(format!("aoc-store-{}", std::process::id()));
+        let downloads = dir.join("downloads");
+        fs::create_dir_all(&downloads).map_err(|e| e.to_string())?;
+        let write = |name: &str, content: &str| fs::write(downloads.join(name), content).unwrap();
+        write("1.txt", "3   4\n4   3\n");
+        write("day02.txt", "7 6 4 2 1\r\n");
+        write("day05.txt", "");
+
+        let store = InputStore::new(dir.join("data"), None)?;
+        let day = |day| years::find(2024, day).unwrap();
+        let result = (|| {
+            let first = find_input(&downloads, 1).ok_or("day 1 not found")?;
+            assert!(store.import(day(1), &first)?);
+            assert!(!store.import(day(1), &first)?);
+            assert!(store.input(2024, 1).is_ok());
+            assert!(store.import(day(2), downloads.join("day02.txt")).is_err());
+            assert!(check(day(5), downloads.join("day05.txt")).is_err());
+            assert!(find_input(&downloads, 3).is_none());
+            write("1.txt", "3   4\n");
+            assert!(store.import(day(1), &first).is_err());
+            Ok(())
+        })();
+        fs::remove_dir_all(&dir).map_err(|e| e.to_string())?;
+        result
+    }
+}
@@ -0,0 +1,20 @@
+//! Advent of Code solutions and the toolkit they are built on.
+//!
+//! The [`aoc`] module holds the pieces shared by every solution: the [`aoc::Solution`] trait,
+//! input readers, grids and maps, answers and errors, along with the runner's statistics and
+//! reports. [`years`] registers each solution by year and day, so a puzzle can be looked up and
+//! solved for any input:
+//!
+//! ```
+//! use aoc2024::aoc::{Answers, Input};
+//! use aoc2024::years;
+//!
+//! let puzzle = years::find(2024, 1).unwrap();
+//! let input = Input::text("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n");
+//! let datum = puzzle.solution().run(puzzle.day, &input, None)?;
+//! assert_eq!(datum.answers, Answers::both(11, 31));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+pub mod aoc;
+pub mod years;
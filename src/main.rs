@@ -1,70 +1,685 @@
-mod aoc;
-mod days;
-
-use crate::aoc::Statistics;
-use crate::days::*;
-use clap::Parser;
+use aoc2024::aoc::baseline::{Baseline, Phase, Timing, Tolerance};
+use aoc2024::aoc::cancel::CancelToken;
+use aoc2024::aoc::error;
+use aoc2024::aoc::manifest::Manifest;
+use aoc2024::aoc::puzzle::{Puzzle, Tag};
+use aoc2024::aoc::report;
+use aoc2024::aoc::scaffold::Scaffold;
+use aoc2024::aoc::stats::{Statistics, Summary};
+use aoc2024::aoc::store::{self, InputStore};
+use aoc2024::aoc::{Answers, Input, SolutionData};
+use aoc2024::years;
+use clap::{Parser, Subcommand, ValueEnum};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::error::Error;
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    /// The day of the puzzle to run (mutually exclusive with --perf)
-    #[arg(group = "mode")]
-    day: Option<usize>,
-    /// Run performance tests (mutually exclusive with day)
-    #[arg(long, group = "mode")]
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// The days of the puzzles to run, e.g. 6 or 3,7,12-15 (defaults to every day)
+    #[arg(value_parser = parse_days)]
+    day: Option<Days>,
+    /// The year of the puzzles to run (defaults to the latest year with solutions)
+    #[arg(short, long)]
+    year: Option<usize>,
+    /// Only solve one part of each puzzle
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Only run puzzles with this tag (may be repeated to require several)
+    #[arg(short, long)]
+    tag: Vec<Tag>,
+    /// List the selected puzzles instead of running them
+    #[arg(short, long)]
+    list: bool,
+    /// Run performance tests, printing statistics instead of answers
+    #[arg(long)]
     perf: bool,
+    /// Read the puzzle input from a custom file, or stdin if "-" (requires a single day)
+    #[arg(short, long, requires = "day")]
+    file: Option<PathBuf>,
+    /// Read inputs from this user's set in the input store
+    #[arg(short, long)]
+    user: Option<String>,
+    /// Check and copy the selected days' inputs from a directory into the input store
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["file", "example", "perf", "bench", "validate"])]
+    import: Option<PathBuf>,
+    /// Check that the selected days' inputs are in the input store and can be parsed
+    #[arg(long, conflicts_with_all = ["file", "example", "perf", "bench"])]
+    validate: bool,
+    /// Run the examples from the puzzle text instead of the real input
+    #[arg(short, long, conflicts_with_all = ["perf", "file"])]
+    example: bool,
+    /// Number of days to run concurrently (0 uses all cores)
+    #[arg(short, long, default_value_t = 1)]
+    jobs: usize,
+    /// Give up on a day that runs for longer than this many seconds
+    #[arg(long, value_name = "SECS", value_parser = parse_timeout, conflicts_with_all = ["example", "bench"])]
+    timeout: Option<Duration>,
+    /// Check the answers against a manifest of known answers
+    #[arg(short, long, value_name = "FILE", conflicts_with = "example")]
+    answers: Option<PathBuf>,
+    /// Benchmark by timing this many runs of each selected day
+    #[arg(short, long, value_name = "RUNS", conflicts_with_all = ["perf", "example"])]
+    bench: Option<usize>,
+    /// Number of untimed runs before benchmarking
+    #[arg(short, long, default_value_t = 1, requires = "bench")]
+    warmup: usize,
+    /// Output format for answers, timings and statistics
+    #[arg(long, value_enum, default_value_t = Format::Text, conflicts_with_all = ["example", "bench"])]
+    format: Format,
+    /// Save each day's parse and solve times to a baseline file
+    #[arg(long, value_name = "FILE", conflicts_with = "example")]
+    save_baseline: Option<PathBuf>,
+    /// Compare each day's parse and solve times against a saved baseline
+    #[arg(long, value_name = "FILE", conflicts_with = "example")]
+    baseline: Option<PathBuf>,
+    /// Percentage a phase may slow down by before it counts as a regression
+    #[arg(
+        long,
+        value_name = "PCT",
+        default_value_t = 10.0,
+        requires = "baseline"
+    )]
+    max_regression: f64,
+    /// Also count a phase as a regression when it slows down by more than this many of the
+    /// baseline's standard deviations
+    #[arg(long, value_name = "N", requires = "baseline")]
+    max_sigma: Option<f64>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate a new day's module from a template and register it
+    New {
+        /// The day of the puzzle
+        day: usize,
+        /// The year of the puzzle (defaults to the latest year with solutions)
+        #[arg(short, long)]
+        year: Option<usize>,
+        /// The title of the puzzle
+        #[arg(long)]
+        title: Option<String>,
+        /// A tag for the puzzle (may be repeated)
+        #[arg(short, long)]
+        tag: Vec<Tag>,
+    },
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let mut solutions = solutions();
-    let args = Args::parse();
+/// A sorted set of days, parsed from a comma-separated list of days and inclusive ranges.
+#[derive(Clone, Debug)]
+struct Days(Vec<usize>);
 
-    match args.day {
-        None => {
-            let mut data = Vec::new();
-            for i in 0..solutions.len() {
-                let datum = solutions[i].run(i + 1)?;
-                if !args.perf {
-                    println!("{datum}");
-                    println!("");
+fn parse_days(arg: &str) -> Result<Days, String> {
+    let mut days = Vec::new();
+    for item in arg.split(',').map(str::trim) {
+        let parse = |n: &str| {
+            n.trim()
+                .parse::<usize>()
+                .map_err(|_| format!("invalid day \"{n}\""))
+        };
+        match item.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse(start)?, parse(end)?);
+                if start > end {
+                    return Err(format!("invalid range \"{item}\""));
                 }
-                data.push(datum);
+                days.extend(start..=end);
             }
-            if args.perf {
-                let stats = Statistics::calc(&data);
-                println!("{stats}");
-                println!("");
-                print_slowest(&stats, 5);
+            None => days.push(parse(item)?),
+        }
+    }
+    days.sort();
+    days.dedup();
+    Ok(Days(days))
+}
+
+fn parse_timeout(arg: &str) -> Result<Duration, String> {
+    arg.parse::<f64>()
+        .ok()
+        .filter(|&secs| secs > 0.0)
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| format!("invalid timeout \"{arg}\""))
+}
+
+#[derive(ValueEnum, Copy, Clone, PartialEq, Eq, Debug)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<(), Box<dyn Error>> {
+    if let Some(Command::New {
+        day,
+        year,
+        title,
+        tag,
+    }) = args.command
+    {
+        let scaffold = Scaffold {
+            year: year.unwrap_or_else(years::latest_year),
+            day,
+            title: title.unwrap_or_else(|| format!("Day {day}")),
+            tags: tag,
+        };
+        for path in scaffold.create(env!("CARGO_MANIFEST_DIR"))? {
+            println!("Wrote {}", path.display());
+        }
+        return Ok(());
+    }
+    let year = args.year.unwrap_or_else(years::latest_year);
+    if years::year(year).next().is_none() {
+        return Err(format!("no solutions for {year}").into());
+    }
+    let mut puzzles = match &args.day {
+        None => years::year(year).collect(),
+        Some(Days(nums)) => nums
+            .iter()
+            .map(|&day| years::find(year, day).ok_or(format!("no solution for {year} day {day}")))
+            .collect::<Result<Vec<_>, _>>()?,
+    };
+    if args.file.is_some() && puzzles.len() != 1 {
+        return Err("--file needs a single day".into());
+    }
+    puzzles.retain(|puzzle| puzzle.has_tags(&args.tag));
+    if args.list {
+        for puzzle in &puzzles {
+            println!("{puzzle}");
+        }
+        return Ok(());
+    }
+    if puzzles.is_empty() {
+        return Err("no puzzles selected".into());
+    }
+    let mut store = InputStore::from_env()?;
+    if let Some(user) = &args.user {
+        store = store.with_user(user)?;
+    }
+    if let Some(dir) = &args.import {
+        return import_inputs(&store, &puzzles, dir);
+    }
+    if args.validate {
+        return validate_inputs(&store, &puzzles);
+    }
+    let part = args.part.map(usize::from);
+    let input = |day| match &args.file {
+        // Stdin is read up front so that a parse error can quote the offending line.
+        Some(path) => Input::from_arg(path)
+            .buffered()
+            .map_err(|e| format!("could not read {}: {e}", path.display())),
+        None => store.input(year, day),
+    };
+
+    if args.example {
+        let mut failures = 0;
+        for puzzle in &puzzles {
+            failures += run_examples(puzzle, part)?;
+        }
+        if failures > 0 {
+            return Err(format!("{failures} example(s) failed").into());
+        }
+        return Ok(());
+    }
+
+    let previous = args.baseline.as_ref().map(Baseline::load).transpose()?;
+
+    if let Some(runs) = args.bench {
+        if runs == 0 {
+            return Err("--bench needs at least one run".into());
+        }
+        let mut current = Baseline::default();
+        for (i, puzzle) in puzzles.iter().enumerate() {
+            if i > 0 {
                 println!("");
             }
+            let input = input(puzzle.day)?;
+            let timing = run_bench(puzzle, &input, part, runs, args.warmup)?;
+            current.insert(year, puzzle.day, timing);
+        }
+        return check_baseline(&args, previous.as_ref(), &current);
+    }
+
+    let manifest = args.answers.as_ref().map(Manifest::load).transpose()?;
+    let mut failures = 0;
+    let text = args.format == Format::Text;
+    let quiet = args.perf || !text;
+
+    let jobs = match args.jobs {
+        0 => thread::available_parallelism()?.get(),
+        jobs => jobs,
+    };
+    if args.format == Format::Csv && !args.perf {
+        println!("{}", report::CSV_HEADER);
+    }
+    let days = puzzles.iter().map(|&p| (p, input(p.day))).collect();
+    let (data, wall_time) = run_all(days, part, jobs, args.timeout, |datum| {
+        match args.format {
+            Format::Text if !args.perf => println!("{datum}"),
+            Format::Csv if !args.perf => println!("{}", report::csv_row(year, datum)),
+            _ => (),
+        }
+        match (&manifest, &datum.error) {
+            (_, Some(error)) if quiet => eprintln!("DAY {:0>2} FAILED: {error}", datum.num),
+            (Some(manifest), None) => failures += verify(manifest, year, datum, part, quiet),
+            _ => (),
+        }
+        if text && !args.perf {
+            println!("");
+        }
+    });
+
+    let stats = Statistics::calc(&data, part, wall_time);
+    match args.format {
+        Format::Text if args.perf => {
+            println!("{stats}");
+            println!("");
+            print_slowest(&stats, 5);
+            println!("");
         }
-        Some(solution_num) => {
-            if solution_num < 1 || solution_num > solutions.len() {
-                return Err("argument out of range".into());
+        Format::Csv if args.perf => {
+            println!("{}", report::CSV_STATISTICS_HEADER);
+            println!("{}", report::csv_statistics(&stats));
+        }
+        Format::Json => println!("{}", report::json(year, &data, &stats)),
+        _ => (),
+    }
+    // Days that failed are left out of the baseline, but the others are still saved and compared.
+    let baseline = check_baseline(&args, previous.as_ref(), &Baseline::from_data(year, &data));
+    if failures > 0 {
+        return Err(format!("{failures} answer(s) did not match the manifest").into());
+    }
+    if !stats.failed.is_empty() {
+        return Err(format!("{} day(s) failed", stats.failed.len()).into());
+    }
+    baseline
+}
+
+/// Save the current timings and compare them against the previous baseline, as requested, and
+/// return an error if any day regressed.
+fn check_baseline(
+    args: &Args,
+    previous: Option<&Baseline>,
+    current: &Baseline,
+) -> Result<(), Box<dyn Error>> {
+    if let Some(path) = &args.save_baseline {
+        current.save(path)?;
+    }
+    let Some(previous) = previous else {
+        return Ok(());
+    };
+    let tolerance = Tolerance {
+        percent: args.max_regression,
+        sigma: args.max_sigma,
+    };
+    let changes = previous.compare(current, &tolerance);
+    let regressions = changes.iter().filter(|c| c.regressed).count();
+    // Machine-readable output goes to stdout, so the comparison goes to stderr.
+    let quiet = args.format != Format::Text;
+    let mut lines = vec!["~- BASELINE -~".to_string()];
+    lines.extend(
+        changes
+            .iter()
+            .filter(|c| c.regressed || c.percent() < -tolerance.percent)
+            .map(|c| c.to_string()),
+    );
+    lines.push(format!(
+        "{regressions} regression(s) in {} phase(s) compared",
+        changes.len()
+    ));
+    for line in lines {
+        if quiet {
+            eprintln!("{line}");
+        } else {
+            println!("{line}");
+        }
+    }
+    if regressions > 0 {
+        return Err(format!("{regressions} phase(s) regressed against the baseline").into());
+    }
+    Ok(())
+}
+
+/// Copy the puzzles' inputs from a directory into the store, checking each one first, and return
+/// an error if any could not be imported. Days without an input in the directory are skipped.
+fn import_inputs(
+    store: &InputStore,
+    puzzles: &[&Puzzle],
+    dir: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let mut imported = 0;
+    let mut failures = 0;
+    for puzzle in puzzles {
+        print!("DAY {:0>2}: ", puzzle.day);
+        let Some(path) = store::find_input(dir, puzzle.day) else {
+            println!("not found");
+            continue;
+        };
+        match store.import(puzzle, &path) {
+            Ok(true) => {
+                imported += 1;
+                println!("imported {}", path.display());
+            }
+            Ok(false) => println!("already imported"),
+            Err(e) => {
+                failures += 1;
+                println!("{e}");
             }
-            println!("{}", solutions[solution_num - 1].run(solution_num)?);
         }
     }
+    println!(
+        "{imported} input(s) imported into {}",
+        store.dir(puzzles[0].year).display()
+    );
+    if failures > 0 {
+        return Err(format!("{failures} input(s) could not be imported").into());
+    }
+    Ok(())
+}
+
+/// Check that every puzzle has an input in the store that its solution can parse, and return an
+/// error if any are missing or invalid.
+fn validate_inputs(store: &InputStore, puzzles: &[&Puzzle]) -> Result<(), Box<dyn Error>> {
+    let mut failures = 0;
+    for puzzle in puzzles {
+        let path = store.path(puzzle.year, puzzle.day);
+        let result = store
+            .input(puzzle.year, puzzle.day)
+            .and_then(|_| store::check(puzzle, &path));
+        match result {
+            Ok(()) => println!("DAY {:0>2}: ok", puzzle.day),
+            Err(e) => {
+                failures += 1;
+                println!("DAY {:0>2}: {e}", puzzle.day);
+            }
+        }
+    }
+    if failures > 0 {
+        return Err(format!("{failures} input(s) missing or invalid").into());
+    }
     Ok(())
 }
 
+/// Print how a day's answers for `year` compare to the manifest and return the number of
+/// mismatches. Only the given part is checked, if any. When `quiet` is set only mismatches are
+/// printed, to stderr.
+fn verify(
+    manifest: &Manifest,
+    year: usize,
+    datum: &SolutionData,
+    part: Option<usize>,
+    quiet: bool,
+) -> usize {
+    let verdicts = manifest.check(year, datum.num, &datum.answers);
+    let checked: Vec<_> = (1..=2)
+        .zip(verdicts)
+        .filter(|(p, _)| part.is_none_or(|part| part == *p))
+        .collect();
+    let failures = checked.iter().filter(|(_, v)| v.failed()).count();
+    if quiet && failures == 0 {
+        return 0;
+    }
+    let line = checked
+        .iter()
+        .map(|(p, v)| format!("Part {p} {v}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let line = format!("Verify: {line}");
+    if quiet {
+        eprintln!("DAY {:0>2} {line}", datum.num);
+    } else {
+        println!("{line}");
+    }
+    failures
+}
+
+/// Run each day's solution on its input, for both parts or only the given one, using a pool of
+/// `jobs` threads and reporting results in order as soon as they are available. A day whose input
+/// could not be found, or whose solution returns an error, panics or runs past the `timeout`, is
+/// reported as failed and the other days carry on. Returns the data for each day along with the
+/// wall-clock time taken.
+fn run_all<F>(
+    days: Vec<(&'static Puzzle, Result<Input, String>)>,
+    part: Option<usize>,
+    jobs: usize,
+    timeout: Option<Duration>,
+    mut report: F,
+) -> (Vec<SolutionData>, Duration)
+where
+    F: FnMut(&SolutionData),
+{
+    let timer = Instant::now();
+    // Panics are reported with the day that failed, so keep them from also being printed as they
+    // happen, out of order with the results.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(record_panic));
+    let queue = Mutex::new(days.into_iter().enumerate());
+    let (sender, receiver) = mpsc::channel();
+    let data = thread::scope(|scope| {
+        for _ in 0..jobs {
+            let sender = sender.clone();
+            let queue = &queue;
+            scope.spawn(move || loop {
+                let next = queue.lock().unwrap().next();
+                let Some((i, (puzzle, input))) = next else {
+                    break;
+                };
+                let datum = match input {
+                    Ok(input) => match timeout {
+                        Some(timeout) => run_with_timeout(puzzle, input, part, timeout),
+                        None => run_isolated(puzzle, &input, part),
+                    },
+                    Err(e) => SolutionData::failed(puzzle.day, e),
+                };
+                if sender.send((i, datum)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut data = Vec::new();
+        for (i, datum) in receiver {
+            pending.insert(i, datum);
+            while let Some(datum) = pending.remove(&data.len()) {
+                report(&datum);
+                data.push(datum);
+            }
+        }
+        data
+    });
+    panic::set_hook(hook);
+    (data, timer.elapsed())
+}
+
+thread_local! {
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// A panic hook that prints nothing, only noting where the panic happened for `run_isolated`.
+fn record_panic(info: &PanicHookInfo) {
+    let location = info.location().map(|l| l.to_string());
+    PANIC_LOCATION.with(|last| *last.borrow_mut() = location);
+}
+
+/// Run a day's solution on a thread of its own, giving up on it once `timeout` has passed. The
+/// solution is then asked to stop, but since it can only do so where it polls `cancel::check` the
+/// thread may carry on in the background until the program exits.
+fn run_with_timeout(
+    puzzle: &'static Puzzle,
+    input: Input,
+    part: Option<usize>,
+    timeout: Duration,
+) -> SolutionData {
+    let token = CancelToken::new();
+    let (sender, receiver) = mpsc::channel();
+    let day_token = token.clone();
+    thread::spawn(move || {
+        let datum = day_token.run(|| run_isolated(puzzle, &input, part));
+        let _ = sender.send(datum);
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(datum) => datum,
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            SolutionData::failed(puzzle.day, format!("timed out after {timeout:?}"))
+        }
+        Err(RecvTimeoutError::Disconnected) => {
+            SolutionData::failed(puzzle.day, "stopped without a result")
+        }
+    }
+}
+
+/// Run a day's solution, turning an error or a panic into a failed day.
+fn run_isolated(puzzle: &Puzzle, input: &Input, part: Option<usize>) -> SolutionData {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        puzzle.solution().run(puzzle.day, input, part)
+    }));
+    match result {
+        Ok(Ok(datum)) => datum,
+        Ok(Err(e)) => SolutionData::failed(puzzle.day, error::render(&*e, input)),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("unknown cause");
+            let message = match PANIC_LOCATION.with(|last| last.borrow_mut().take()) {
+                Some(location) => format!("panicked at {location}: {message}"),
+                None => format!("panicked: {message}"),
+            };
+            SolutionData::failed(puzzle.day, message)
+        }
+    }
+}
+
+/// Time `runs` runs of a day after `warmup` untimed ones, re-creating the solution each time, and
+/// print summary statistics for the parse and solve phases and for each part that was run.
+/// Returns the mean and spread of the parse and solve phases.
+fn run_bench(
+    puzzle: &Puzzle,
+    input: &Input,
+    part: Option<usize>,
+    runs: usize,
+    warmup: usize,
+) -> Result<Timing, Box<dyn Error>> {
+    let mut parse_times = Vec::new();
+    let mut solve_times = Vec::new();
+    let mut part1_times = Vec::new();
+    let mut part2_times = Vec::new();
+    for i in 0..warmup + runs {
+        let datum = puzzle
+            .solution()
+            .run(puzzle.day, input, part)
+            .map_err(|e| describe(puzzle, &*e, input))?;
+        if i >= warmup {
+            parse_times.push(datum.parse_time);
+            solve_times.push(datum.solve_time());
+            part1_times.push(datum.part1_time);
+            part2_times.push(datum.part2_time);
+        }
+    }
+    let parse = Summary::from_durations(&parse_times).ok_or("no runs")?;
+    let solve = Summary::from_durations(&solve_times).ok_or("no runs")?;
+    println!("~- DAY {:0>2} ({runs} runs) -~", puzzle.day);
+    println!("Parse: {parse}");
+    println!("Solve: {solve}");
+    for (p, times) in [(1, &part1_times), (2, &part2_times)] {
+        if part.is_none_or(|part| part == p) {
+            if let Some(summary) = Summary::from_durations(times) {
+                println!("Part {p}: {summary}");
+            }
+        }
+    }
+    Ok(Timing {
+        parse: Phase::from_summary(&parse),
+        solve: Phase::from_summary(&solve),
+    })
+}
+
+fn run_examples(puzzle: &Puzzle, part: Option<usize>) -> Result<usize, Box<dyn Error>> {
+    let examples = puzzle.solution().examples();
+    let mut failures = 0;
+    for example in examples {
+        let expected = example.answers.only(part);
+        if expected == Answers::None {
+            continue;
+        }
+        let datum = example
+            .run(puzzle, part)
+            .map_err(|e| describe(puzzle, &*e, &example.input()))?;
+        println!("{datum}");
+        if datum.answers.matches(&expected) {
+            println!("Example {}: ok", example.filename);
+        } else {
+            failures += 1;
+            println!("Example {}: FAILED, expected", example.filename);
+            println!("{expected}");
+        }
+        println!("");
+    }
+    Ok(failures)
+}
+
+/// Describe a day's failure, quoting the offending line of the input for parse errors.
+fn describe(puzzle: &Puzzle, error: &(dyn Error + 'static), input: &Input) -> String {
+    format!("DAY {:0>2}: {}", puzzle.day, error::render(error, input))
+}
+
 fn print_slowest(stats: &Statistics, n: usize) {
-    let mut slowest = stats.complete.clone();
-    slowest.sort_by(|a, b| (b.parse_time + b.solve_time).cmp(&(a.parse_time + a.solve_time)));
-    let avg = stats.avg / 1000.0;
-    let stddev = stats.stddev / 1000.0;
     println!("~- SLOWEST -~");
-    for line in slowest[0..n].iter().map(|s| {
-        let time = (s.parse_time + s.solve_time).as_micros() as f64 / 1000.0;
-        format!(
-            "DAY {:0>2}: {:.3}ms ({:+.2}σ)",
-            s.num,
-            time,
-            (time - avg) / stddev
-        )
-    }) {
-        println!("{line}");
+    for s in stats.slowest(n) {
+        let time = (s.parse_time + s.solve_time()).as_secs_f64() * 1000.0;
+        print!("DAY {:0>2}: {time:.3}ms", s.num);
+        match &stats.total {
+            Some(total) if total.sample_stddev() > 0.0 => {
+                println!(" ({:+.2}σ)", (time - total.mean) / total.sample_stddev())
+            }
+            _ => println!(""),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn days() -> Result<(), String> {
+        assert_eq!(parse_days("6")?.0, vec![6]);
+        assert_eq!(parse_days("12-15,3, 7,13")?.0, vec![3, 7, 12, 13, 14, 15]);
+        assert!(parse_days("15-12").is_err());
+        assert!(parse_days("3,,7").is_err());
+        assert!(parse_days("x-2").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn timeout() -> Result<(), String> {
+        assert_eq!(parse_timeout("10")?, Duration::from_secs(10));
+        assert_eq!(parse_timeout("0.25")?, Duration::from_millis(250));
+        assert!(parse_timeout("0").is_err());
+        assert!(parse_timeout("-1").is_err());
+        assert!(parse_timeout("soon").is_err());
+        Ok(())
     }
 }
@@ -0,0 +1,51 @@
+pub mod y2024;
+
+use crate::aoc::puzzle::Puzzle;
+
+/// The solutions for each year, in order of year.
+static YEARS: &[&[Puzzle]] = &[y2024::PUZZLES];
+
+/// Every puzzle with a solution, in order of year and day.
+pub fn puzzles() -> impl Iterator<Item = &'static Puzzle> {
+    YEARS.iter().flat_map(|puzzles| puzzles.iter())
+}
+
+/// The most recent year with any solutions.
+pub fn latest_year() -> usize {
+    puzzles().map(|p| p.year).max().unwrap_or_default()
+}
+
+/// The puzzles with solutions from the given year, in order of day.
+pub fn year(year: usize) -> impl Iterator<Item = &'static Puzzle> {
+    puzzles().filter(move |p| p.year == year)
+}
+
+/// The puzzle for the given year and day, if it has a solution.
+///
+/// ```
+/// let puzzle = aoc2024::years::find(2024, 6).unwrap();
+/// assert_eq!(puzzle.to_string(), "2024 DAY 06: Guard Gallivant [grid, simulation]");
+/// assert!(aoc2024::years::find(2024, 26).is_none());
+/// ```
+pub fn find(year: usize, day: usize) -> Option<&'static Puzzle> {
+    puzzles().find(|p| p.year == year && p.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn registry() {
+        let keys = puzzles().map(|p| (p.year, p.day)).collect::<Vec<_>>();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(keys, sorted);
+        for puzzles in YEARS {
+            assert!(puzzles.iter().all(|p| p.year == puzzles[0].year));
+        }
+        assert_eq!(find(2024, 6).map(|p| p.title), Some("Guard Gallivant"));
+        assert!(find(2024, 26).is_none());
+        assert!(find(2023, 6).is_none());
+    }
+}
@@ -0,0 +1,108 @@
+use crate::aoc::answer::Answer;
+use crate::aoc::error::Line;
+use crate::aoc::puzzle::Puzzle;
+use crate::aoc::{read_lines, Answers, Example, Input, Solution};
+use std::collections::HashMap;
+use std::error::Error;
+
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 1,
+    title: "Historian Hysteria",
+    tags: &[],
+    new: || Box::new(Day01::new()),
+};
+
+#[derive(Debug)]
+struct Day01 {
+    list1: Vec<i32>,
+    list2: Vec<i32>,
+}
+
+impl Day01 {
+    pub fn new() -> Self {
+        Day01 {
+            list1: Vec::new(),
+            list2: Vec::new(),
+        }
+    }
+}
+
+impl Solution for Day01 {
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        for (i, text) in read_lines(input)?.enumerate() {
+            let text = text?;
+            let line = Line::new(i, &text);
+            let mut iter = text.split("   ");
+            let str1 = iter.next().ok_or_else(|| line.missing("a location ID"))?;
+            let num1 = line.parse::<i32>(str1)?;
+            let str2 = iter
+                .next()
+                .ok_or_else(|| line.missing("a second location ID"))?;
+            let num2 = line.parse::<i32>(str2)?;
+            self.list1.push(num1);
+            self.list2.push(num2);
+        }
+        Ok(())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day01_01.txt",
+            Answers::both(11, 31),
+        )]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        self.list1.sort();
+        self.list2.sort();
+
+        let mut total_distance = 0;
+        for (l, r) in self.list1.iter().zip(self.list2.iter()) {
+            total_distance += (l - r).abs();
+        }
+        Ok(total_distance.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let mut cum_score = 0;
+        let mut score_by_id = HashMap::new();
+        for location_id in &self.list1 {
+            if let Some(score) = score_by_id.get(&location_id) {
+                cum_score += score;
+            } else {
+                let mut count = 0;
+                for other_id in &self.list2 {
+                    if other_id == location_id {
+                        count += 1;
+                    }
+                }
+                let score = location_id * count;
+                score_by_id.insert(location_id, score);
+                cum_score += score;
+            }
+        }
+
+        Ok(cum_score.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::{check_examples, default_input};
+    #[test]
+    #[ignore = "needs the private input"]
+    fn solution() -> Result<(), Box<dyn Error>> {
+        let mut solution = Day01::new();
+        solution.parse_input(&default_input(2024, 1)?)?;
+        let answers = solution.solve()?;
+        assert_eq!(answers, Answers::both(1603498, 25574739));
+        Ok(())
+    }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
+}
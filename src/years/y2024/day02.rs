@@ -1,8 +1,19 @@
-use crate::aoc::{read_lines, Answers, Solution};
+use crate::aoc::answer::Answer;
+use crate::aoc::error::Line;
+use crate::aoc::puzzle::Puzzle;
+use crate::aoc::{read_lines, Answers, Example, Input, Solution};
 use std::error::Error;
 
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 2,
+    title: "Red-Nosed Reports",
+    tags: &[],
+    new: || Box::new(Day02::new()),
+};
+
 #[derive(Debug)]
-pub struct Day02 {
+struct Day02 {
     reports: Vec<Vec<i32>>,
 }
 
@@ -15,24 +26,39 @@ impl Day02 {
 }
 
 impl Solution for Day02 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let lines = read_lines("./data/day02.txt")?;
-        for line in lines.flatten() {
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        for (i, text) in read_lines(input)?.enumerate() {
+            let text = text?;
+            let line = Line::new(i, &text);
             self.reports.push(
-                line.split(" ")
-                    .map(|n| n.parse::<i32>())
+                text.split(" ")
+                    .map(|n| line.parse::<i32>(n))
                     .collect::<Result<Vec<_>, _>>()?,
             );
         }
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
-        let mut count = 0;
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day02_01.txt",
+            Answers::both(2, 4),
+        )]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let count = self
+            .reports
+            .iter()
+            .filter(|nums| check_safety(nums))
+            .count();
+        Ok(count.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
         let mut dampener_count = 0;
         for nums in &self.reports {
             if check_safety(&nums) {
-                count += 1;
                 dampener_count += 1;
             } else {
                 for i in 0..nums.len() {
@@ -44,7 +70,7 @@ impl Solution for Day02 {
                 }
             }
         }
-        Ok(Answers::both(count, dampener_count))
+        Ok(dampener_count.into())
     }
 }
 
@@ -77,12 +103,19 @@ fn check_safety(nums: &Vec<i32>) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day02::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 2)?)?;
         let answers = solution.solve()?;
         assert_eq!(answers, Answers::both(663, 692));
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
@@ -1,8 +1,18 @@
-use crate::aoc::{read_chars, Answers, Solution};
+use crate::aoc::answer::Answer;
+use crate::aoc::puzzle::Puzzle;
+use crate::aoc::{read_chars, Answers, Example, Input, Solution};
 use std::error::Error;
 
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 3,
+    title: "Mull It Over",
+    tags: &[],
+    new: || Box::new(Day03::new()),
+};
+
 #[derive(Debug)]
-pub struct Day03 {
+struct Day03 {
     chars: Vec<char>,
 }
 
@@ -13,16 +23,26 @@ impl Day03 {
 }
 
 impl Solution for Day03 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        self.chars.extend(read_chars("./data/day03.txt")?.flatten());
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        for c in read_chars(input)? {
+            self.chars.push(c?);
+        }
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
-        Ok(Answers::both(
-            compute_total(&self.chars, false)?,
-            compute_total(&self.chars, true)?,
-        ))
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("test_cases/2024/day03_01.txt", Answers::part1(161)),
+            Example::new("test_cases/2024/day03_02.txt", Answers::part2(48)),
+        ]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        Ok(compute_total(&self.chars, false)?.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        Ok(compute_total(&self.chars, true)?.into())
     }
 }
 
@@ -139,12 +159,19 @@ fn compute_total(chars: &Vec<char>, handle_dos: bool) -> Result<i32, Box<dyn Err
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day03::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 3)?)?;
         let answers = solution.solve()?;
         assert_eq!(answers, Answers::both(161289189, 83595109));
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
@@ -0,0 +1,110 @@
+use crate::aoc::answer::Answer;
+use crate::aoc::grid::{Edges, Grid, Offset, ADJACENT};
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_to_string, Answers, Example, Input, Position, Solution};
+use std::error::Error;
+
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 4,
+    title: "Ceres Search",
+    tags: &[Tag::Grid],
+    new: || Box::new(Day04::new()),
+};
+
+#[derive(Debug)]
+struct Day04 {
+    wordsearch: Grid<u8>,
+}
+
+impl Day04 {
+    pub fn new() -> Day04 {
+        Day04 {
+            wordsearch: Grid::new(),
+        }
+    }
+
+    /// The letter `by` steps of `offset` away from `pos`, if that is still in the grid.
+    fn letter(&self, pos: Position, offset: Offset, by: isize) -> Option<u8> {
+        let offset = (offset.0 * by, offset.1 * by);
+        let grid = &self.wordsearch;
+        Edges::Bounded
+            .step(pos, offset, grid.width, grid.height)
+            .map(|pos| grid[pos])
+    }
+
+    /// The number of times XMAS is spelled out from `pos` in any direction.
+    fn count_xmas(&self, pos: Position) -> usize {
+        ADJACENT
+            .iter()
+            .filter(|&&offset| {
+                (0..4).all(|k| self.letter(pos, offset, k as isize) == Some(b"XMAS"[k]))
+            })
+            .count()
+    }
+
+    /// Whether `pos` is the middle of two diagonal MASes crossing in an X.
+    fn check_cross(&self, pos: Position) -> bool {
+        let mas = |from: Offset| {
+            let ends = (self.letter(pos, from, 1), self.letter(pos, from, -1));
+            matches!(ends, (Some(b'M'), Some(b'S')) | (Some(b'S'), Some(b'M')))
+        };
+        self.wordsearch[pos] == b'A' && mas((-1, -1)) && mas((1, -1))
+    }
+}
+
+impl Solution for Day04 {
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        let letter = |c: char| match c {
+            'A'..='Z' => Ok(c as u8),
+            _ => Err(format!("invalid character '{c}'")),
+        };
+        self.wordsearch = Grid::parse(&read_to_string(input)?, letter)?;
+        Ok(())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day04_01.txt",
+            Answers::both(18, 9),
+        )]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let xmas_total: usize = self
+            .wordsearch
+            .positions()
+            .map(|pos| self.count_xmas(pos))
+            .sum();
+        Ok(xmas_total.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let cross_total = self
+            .wordsearch
+            .positions()
+            .filter(|&pos| self.check_cross(pos))
+            .count();
+        Ok(cross_total.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::{check_examples, default_input};
+    #[test]
+    #[ignore = "needs the private input"]
+    fn solution() -> Result<(), Box<dyn Error>> {
+        let mut solution = Day04::new();
+        solution.parse_input(&default_input(2024, 4)?)?;
+        let answers = solution.solve()?;
+        assert_eq!(answers, Answers::both(2414, 1871));
+        Ok(())
+    }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
+}
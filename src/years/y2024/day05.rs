@@ -0,0 +1,139 @@
+use crate::aoc::answer::Answer;
+use crate::aoc::error::Line;
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_lines, Answers, Example, Input, Solution};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::error::Error;
+
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 5,
+    title: "Print Queue",
+    tags: &[Tag::Graph],
+    new: || Box::new(Day05::new()),
+};
+
+#[derive(Debug)]
+struct Day05 {
+    rules: HashMap<usize, Vec<usize>>,
+    updates: Vec<Vec<usize>>,
+}
+
+impl Day05 {
+    pub fn new() -> Self {
+        Day05 {
+            rules: HashMap::new(),
+            updates: Vec::new(),
+        }
+    }
+
+    /// Whether no page is printed before a page that has to come before it. Pages without any
+    /// rules of their own can be printed anywhere.
+    fn is_correct(&self, pages: &[usize]) -> bool {
+        let num_pages = pages.len();
+        for i in 0..num_pages {
+            let page = pages[i];
+            for j in i + 1..num_pages {
+                let other = pages[j];
+                let Some(afters) = self.rules.get(&other) else {
+                    continue;
+                };
+                if afters.contains(&page) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+impl Solution for Day05 {
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        let mut in_rules_section = true;
+        for (i, text) in read_lines(input)?.enumerate() {
+            let text = text?;
+            let line = Line::new(i, &text);
+            if &text == "" {
+                in_rules_section = false;
+            } else if in_rules_section {
+                let (before, after) = text
+                    .split_once('|')
+                    .ok_or_else(|| line.missing("a second page in the rule"))?;
+                self.rules
+                    .entry(line.parse::<usize>(before)?)
+                    .or_insert_with(Vec::new)
+                    .push(line.parse::<usize>(after)?);
+            } else {
+                self.updates.push(
+                    text.split(',')
+                        .map(|s| line.parse::<usize>(s))
+                        .collect::<Result<Vec<_>, _>>()?,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("test_cases/2024/day05_01.txt", Answers::both(143, 123)),
+            // Pages 13, 29, 61 and 75 have no rules of their own.
+            Example::new("test_cases/2024/day05_02.txt", Answers::both(114, 53)),
+        ]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let mut correct_total = 0;
+        for pages in &self.updates {
+            if self.is_correct(pages) {
+                correct_total += pages[pages.len() / 2];
+            }
+        }
+        Ok(correct_total.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let mut corrected_total = 0;
+        for pages in &self.updates {
+            if self.is_correct(pages) {
+                continue;
+            }
+            let mut pages = pages.clone();
+            pages.sort_by(|a, b| {
+                let default = &Vec::new();
+                let afters_a = self.rules.get(&a).unwrap_or(default);
+                let afters_b = self.rules.get(&b).unwrap_or(default);
+                if afters_a.contains(&b) {
+                    return Ordering::Less;
+                }
+                if afters_b.contains(&a) {
+                    return Ordering::Greater;
+                }
+                Ordering::Equal
+            });
+            corrected_total += pages[pages.len() / 2];
+        }
+        Ok(corrected_total.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::{check_examples, default_input};
+    #[test]
+    #[ignore = "needs the private input"]
+    fn solution() -> Result<(), Box<dyn Error>> {
+        let mut solution = Day05::new();
+        solution.parse_input(&default_input(2024, 5)?)?;
+        let answers = solution.solve()?;
+        assert_eq!(answers, Answers::both(5639, 5273));
+        Ok(())
+    }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
+}
@@ -1,13 +1,25 @@
+use crate::aoc::answer::Answer;
+use crate::aoc::cancel;
 use crate::aoc::grid::Grid;
-use crate::aoc::{read_chars, Answers, Direction, Map, Position, Solution};
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_to_string, Answers, Direction, Example, Input, Map, Position, Solution};
 use rustc_hash::FxHashSet;
 use std::error::Error;
 use std::fmt::Display;
 use std::hash::Hash;
 
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 6,
+    title: "Guard Gallivant",
+    tags: &[Tag::Grid, Tag::Simulation],
+    new: || Box::new(Day06::new()),
+};
+
 #[derive(Debug)]
-pub struct Day06 {
+struct Day06 {
     grid: Grid<Cell>,
+    start: Position,
     guard: Guard,
     history: Vec<Guard>,
     visited: FxHashSet<Position>,
@@ -16,45 +28,34 @@ pub struct Day06 {
 }
 
 impl Solution for Day06 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let filename = "./data/day06.txt";
-        let width = if filename.contains("/data/") { 130 } else { 10 };
-        let height = width;
-        self.grid = Grid::fill(Cell::Empty, width, height);
-        let mut y = 0;
-        let mut x = 0;
-        let chars = read_chars(filename)?;
-        for char in chars.flatten() {
-            match char {
-                '.' => {
-                    x += 1;
-                }
-                '#' => {
-                    self.grid[(x, y)] = Cell::Obstacle;
-                    x += 1;
-                }
-                '^' => {
-                    self.place_guard((x, y));
-                    x += 1;
-                }
-                '\n' => {
-                    y += 1;
-                    x = 0;
-                }
-                _ => {
-                    return Err("invalid character".into());
-                }
-            }
-        }
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        let (grid, markers) = Grid::parse_with_markers(&read_to_string(input)?, "^", Cell::parse)?;
+        self.grid = grid;
+        self.start = markers.one('^')?;
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day06_01.txt",
+            Answers::both(41, 6),
+        )]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        self.place_guard(self.start);
         self.simulate(SimulationType::History);
-        let part1 = self.visited.len();
+        Ok(self.visited.len().into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        self.place_guard(self.start);
+        self.simulate(SimulationType::History);
+        self.loops.clear();
         let history = self.history[0..self.history.len() - 1].to_vec();
 
         for i in 0..history.len() {
+            cancel::check()?;
             let guard = history[i];
             if let Some((pos, _)) = self.go(guard.direction, &guard.position) {
                 if self.loops.contains(&pos) || self.grid[pos] == Cell::Obstacle {
@@ -70,9 +71,7 @@ impl Solution for Day06 {
                 self.grid[pos] = Cell::Empty;
             }
         }
-        let part2 = self.loops.len();
-
-        Ok(Answers::both(part1, part2))
+        Ok(self.loops.len().into())
     }
 }
 
@@ -96,6 +95,7 @@ impl Day06 {
     pub fn new() -> Day06 {
         Day06 {
             grid: Grid::new(),
+            start: (0, 0),
             guard: Guard::new(),
             history: Vec::new(),
             visited: FxHashSet::default(),
@@ -152,6 +152,16 @@ pub enum Cell {
     Obstacle,
 }
 
+impl Cell {
+    fn parse(c: char) -> Result<Cell, String> {
+        match c {
+            '.' | '^' => Ok(Cell::Empty),
+            '#' => Ok(Cell::Obstacle),
+            _ => Err(format!("invalid character '{c}'")),
+        }
+    }
+}
+
 impl Display for Cell {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -199,12 +209,19 @@ enum SimulationResult {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day06::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 6)?)?;
         let answers = solution.solve()?;
-        assert_eq!(answers, Answers::both(4826usize, 1721usize));
+        assert_eq!(answers, Answers::both(4826, 1721));
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
@@ -1,8 +1,19 @@
-use crate::aoc::{read_lines, Answers, Solution};
+use crate::aoc::answer::Answer;
+use crate::aoc::error::Line;
+use crate::aoc::puzzle::Puzzle;
+use crate::aoc::{read_lines, Answers, Example, Input, Solution};
 use std::error::Error;
 
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 7,
+    title: "Bridge Repair",
+    tags: &[],
+    new: || Box::new(Day07::new()),
+};
+
 #[derive(Debug)]
-pub struct Day07 {
+struct Day07 {
     equations: Vec<(u64, Vec<u64>)>,
 }
 
@@ -15,35 +26,48 @@ impl Day07 {
 }
 
 impl Solution for Day07 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let lines = read_lines("./data/day07.txt")?;
-        for line in lines.flatten() {
-            let chunks: Vec<&str> = line.split(": ").collect();
-            if chunks.len() != 2 {
-                return Err("invalid line".into());
-            }
-            let test_val = chunks[0].parse::<u64>()?;
-            let nums: Vec<u64> = chunks[1]
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        for (i, text) in read_lines(input)?.enumerate() {
+            let text = text?;
+            let line = Line::new(i, &text);
+            let (test_val, nums) = text
+                .split_once(": ")
+                .ok_or_else(|| line.missing("\": \" after the test value"))?;
+            let test_val = line.parse::<u64>(test_val)?;
+            let nums: Vec<u64> = nums
                 .split(" ")
-                .map(|s| s.parse::<u64>())
+                .map(|s| line.parse::<u64>(s))
                 .collect::<Result<_, _>>()?;
             self.equations.push((test_val, nums));
         }
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day07_01.txt",
+            Answers::both(3749, 11387),
+        )]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
         let mut total = 0;
-        let mut total_with_concat = 0;
         for (test_val, nums) in &self.equations {
             if check_equation(*test_val, &nums, false) {
                 total += test_val;
             }
+        }
+        Ok(total.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let mut total_with_concat = 0;
+        for (test_val, nums) in &self.equations {
             if check_equation(*test_val, &nums, true) {
                 total_with_concat += test_val;
             }
         }
-        Ok(Answers::both(total, total_with_concat))
+        Ok(total_with_concat.into())
     }
 }
 
@@ -89,12 +113,19 @@ fn check_equation(test_val: u64, nums: &[u64], allow_concat: bool) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day07::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 7)?)?;
         let answers = solution.solve()?;
         assert_eq!(answers, Answers::both(6392012777720u64, 61561126043536u64));
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
@@ -1,9 +1,20 @@
-use crate::aoc::{read_chars, Answers, Solution};
+use crate::aoc::answer::Answer;
+use crate::aoc::error::AocError;
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_chars, Answers, Example, Input, Solution};
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
 
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 8,
+    title: "Resonant Collinearity",
+    tags: &[Tag::Grid, Tag::NumberTheory],
+    new: || Box::new(Day08::new()),
+};
+
 #[derive(Debug)]
-pub struct Day08 {
+struct Day08 {
     antennas_by_freq: HashMap<char, Vec<Antenna>>,
     width: i32,
     height: i32,
@@ -48,11 +59,12 @@ impl Day08 {
 }
 
 impl Solution for Day08 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
         let mut i = 0;
         let mut j = 0;
         let mut width = 0;
-        for c in read_chars("./data/day08.txt")?.flatten() {
+        for c in read_chars(input)? {
+            let c = c?;
             match c {
                 '0'..='9' | 'a'..='z' | 'A'..='Z' => {
                     if let Some(antennas) = self.antennas_by_freq.get_mut(&c) {
@@ -73,7 +85,10 @@ impl Solution for Day08 {
                     i = 0;
                     j += 1;
                 }
-                _ => return Err(format!("invalid char {}", c).into()),
+                _ => {
+                    let message = format!("invalid character '{c}'");
+                    return Err(AocError::parse(j as usize + 1, i as usize + 1, message).into());
+                }
             }
         }
 
@@ -83,21 +98,35 @@ impl Solution for Day08 {
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day08_01.txt",
+            Answers::both(14, 34),
+        )]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
         let mut antinodes = HashSet::new();
-        let mut harmonic_antinodes = HashSet::new();
-        for (_, antennas) in &self.antennas_by_freq {
+        for antennas in self.antennas_by_freq.values() {
             for (a, b) in pairs(antennas) {
                 if let Some(antinode) = self.find_antinode(a.pos, b.pos) {
                     antinodes.insert(antinode);
                 }
+            }
+        }
+        Ok(antinodes.len().into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let mut harmonic_antinodes = HashSet::new();
+        for antennas in self.antennas_by_freq.values() {
+            for (a, b) in pairs(antennas) {
                 for antinode in self.find_harmonic_antinodes(a.pos, b.pos) {
                     harmonic_antinodes.insert(antinode);
                 }
             }
         }
-
-        Ok(Answers::both(antinodes.len(), harmonic_antinodes.len()))
+        Ok(harmonic_antinodes.len().into())
     }
 }
 
@@ -137,12 +166,19 @@ fn sub(pos1: (i32, i32), pos2: (i32, i32)) -> (i32, i32) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day08::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 8)?)?;
         let answers = solution.solve()?;
-        assert_eq!(answers, Answers::both(364usize, 1231usize));
+        assert_eq!(answers, Answers::both(364, 1231));
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
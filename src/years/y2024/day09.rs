@@ -1,10 +1,21 @@
-use crate::aoc::{read_chars, Answers, Solution};
+use crate::aoc::answer::Answer;
+use crate::aoc::error::AocError;
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_chars, Answers, Example, Input, Solution};
 use std::collections::VecDeque;
 use std::error::Error;
 use std::usize;
 
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 9,
+    title: "Disk Fragmenter",
+    tags: &[Tag::Simulation],
+    new: || Box::new(Day09::new()),
+};
+
 #[derive(Debug)]
-pub struct Day09 {
+struct Day09 {
     blocks: Vec<Block>,
     rle: Vec<(Block, usize, usize)>,
 }
@@ -19,10 +30,11 @@ impl Day09 {
 }
 
 impl Solution for Day09 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let chars = read_chars("./data/day09.txt")?;
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        let chars = read_chars(input)?;
         let mut buff = VecDeque::new();
-        for (i, c) in chars.flatten().enumerate() {
+        for (i, c) in chars.enumerate() {
+            let c = c?;
             if c == '\n' {
                 if i % 2 != 0 {
                     let last = buff.len() - 1;
@@ -30,7 +42,10 @@ impl Solution for Day09 {
                 }
                 break;
             }
-            let size = c.to_string().parse::<usize>()?;
+            let size = c
+                .to_digit(10)
+                .ok_or_else(|| AocError::parse(1, i + 1, format!("invalid size '{c}'")))?
+                as usize;
             buff.push_back(size);
             if buff.len() > 2 {
                 buff.pop_front();
@@ -45,12 +60,23 @@ impl Solution for Day09 {
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
-        compact_by_block(&mut self.blocks);
-        let checksum1 = compute_checksum(&self.blocks);
-        compact_by_chunk2(&mut self.rle);
-        let checksum2 = compute_checksum(&expand_rle(&self.rle));
-        Ok(Answers::both(checksum1, checksum2))
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day09_01.txt",
+            Answers::both(1928, 2858),
+        )]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let mut blocks = self.blocks.clone();
+        compact_by_block(&mut blocks);
+        Ok(compute_checksum(&blocks).into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let mut rle = self.rle.clone();
+        compact_by_chunk2(&mut rle);
+        Ok(compute_checksum(&expand_rle(&rle)).into())
     }
 }
 
@@ -133,10 +159,12 @@ fn compute_checksum(blocks: &Vec<Block>) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day09::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 9)?)?;
         let answers = solution.solve()?;
         assert_eq!(
             answers,
@@ -144,4 +172,9 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
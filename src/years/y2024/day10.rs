@@ -0,0 +1,109 @@
+use crate::aoc::answer::Answer;
+use crate::aoc::grid::Grid;
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_to_string, Answers, Example, Input, Position, Solution};
+use std::collections::HashSet;
+use std::error::Error;
+
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 10,
+    title: "Hoof It",
+    tags: &[Tag::Grid, Tag::Graph],
+    new: || Box::new(Day10::new()),
+};
+
+#[derive(Debug)]
+struct Day10 {
+    trailheads: Vec<Position>,
+    grid: Grid<u32>,
+}
+
+impl Day10 {
+    pub fn new() -> Day10 {
+        Day10 {
+            trailheads: Vec::new(),
+            grid: Grid::new(),
+        }
+    }
+
+    fn find_summits(&self, pos: Position, summits: &mut HashSet<Position>) {
+        let height = self.grid[pos];
+        if height == 9 {
+            summits.insert(pos);
+            return;
+        }
+        for (next, &next_height) in self.grid.neighbors(pos) {
+            if next_height.saturating_sub(height) == 1 {
+                self.find_summits(next, summits);
+            }
+        }
+    }
+
+    fn compute_rating(&self, pos: Position) -> usize {
+        let height = self.grid[pos];
+        if height == 9 {
+            return 1;
+        }
+        self.grid
+            .neighbors(pos)
+            .filter(|(_, &next_height)| next_height.saturating_sub(height) == 1)
+            .map(|(next, _)| self.compute_rating(next))
+            .sum()
+    }
+}
+
+impl Solution for Day10 {
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        let height = |c: char| c.to_digit(10).ok_or(format!("invalid character '{c}'"));
+        let (grid, markers) = Grid::parse_with_markers(&read_to_string(input)?, "0", height)?;
+        self.grid = grid;
+        self.trailheads = markers.all('0').to_vec();
+        Ok(())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day10_01.txt",
+            Answers::both(36, 81),
+        )]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let mut score = 0;
+        for trailhead in &self.trailheads {
+            let mut summits = HashSet::new();
+            self.find_summits(*trailhead, &mut summits);
+            score += summits.len();
+        }
+        Ok(score.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let mut rating = 0;
+        for trailhead in &self.trailheads {
+            rating += self.compute_rating(*trailhead);
+        }
+        Ok(rating.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::{check_examples, default_input};
+    #[test]
+    #[ignore = "needs the private input"]
+    fn solution() -> Result<(), Box<dyn Error>> {
+        let mut solution = Day10::new();
+        solution.parse_input(&default_input(2024, 10)?)?;
+        let answers = solution.solve()?;
+        assert_eq!(answers, Answers::both(629, 1242));
+        Ok(())
+    }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
+}
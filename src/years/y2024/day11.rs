@@ -1,11 +1,21 @@
-use crate::aoc::{Answers, Solution};
+use crate::aoc::answer::Answer;
+use crate::aoc::error::parse_token;
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_to_string, Answers, Example, Input, Solution};
 use std::collections::HashMap;
 use std::error::Error;
-use std::fs::read_to_string;
 use std::num::ParseIntError;
 
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 11,
+    title: "Plutonian Pebbles",
+    tags: &[Tag::Simulation],
+    new: || Box::new(Day11::new()),
+};
+
 #[derive(Debug)]
-pub struct Day11 {
+struct Day11 {
     stone_count_by_num: HashMap<u64, u64>,
 }
 
@@ -15,27 +25,39 @@ impl Day11 {
             stone_count_by_num: HashMap::new(),
         }
     }
+
+    fn count_after(&self, blinks: usize) -> Result<u64, ParseIntError> {
+        let mut stone_count_by_num = self.stone_count_by_num.clone();
+        for _ in 0..blinks {
+            stone_count_by_num = blink(&stone_count_by_num)?;
+        }
+        Ok(get_total(&stone_count_by_num))
+    }
 }
 
 impl Solution for Day11 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let raw = read_to_string("./data/day11.txt")?;
-        for stone in raw.split_whitespace().map(|s| s.parse::<u64>()).flatten() {
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        let raw = read_to_string(input)?;
+        for stone in raw.split_whitespace() {
+            let stone = parse_token::<u64>(&raw, stone)?;
             *self.stone_count_by_num.entry(stone).or_insert(0) += 1;
         }
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
-        for _ in 0..25 {
-            self.stone_count_by_num = blink(&self.stone_count_by_num)?;
-        }
-        let total1 = get_total(&self.stone_count_by_num);
-        for _ in 0..50 {
-            self.stone_count_by_num = blink(&self.stone_count_by_num)?;
-        }
-        let total2 = get_total(&self.stone_count_by_num);
-        Ok(Answers::both(total1, total2))
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day11_01.txt",
+            Answers::part1(55312),
+        )]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        Ok(self.count_after(25)?.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        Ok(self.count_after(75)?.into())
     }
 }
 
@@ -78,12 +100,19 @@ fn blink(stone_count_by_num: &HashMap<u64, u64>) -> Result<HashMap<u64, u64>, Pa
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day11::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 11)?)?;
         let answers = solution.solve()?;
-        assert_eq!(answers, Answers::both(199753u64, 239413123020116u64));
+        assert_eq!(answers, Answers::both(199753, 239413123020116u64));
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
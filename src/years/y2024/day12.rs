@@ -1,108 +1,99 @@
-use crate::aoc::{read_lines, Answers, Solution};
+use crate::aoc::answer::Answer;
+use crate::aoc::grid::Grid;
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_to_string, Answers, Example, Input, Solution};
 use std::collections::HashSet;
 use std::error::Error;
 
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 12,
+    title: "Garden Groups",
+    tags: &[Tag::Grid, Tag::Graph],
+    new: || Box::new(Day12::new()),
+};
+
 #[derive(Debug)]
-pub struct Day12 {
-    grid: Vec<Vec<char>>,
-    width: i32,
-    height: i32,
+struct Day12 {
+    grid: Grid<char>,
 }
 
 impl Day12 {
     pub fn new() -> Day12 {
-        Day12 {
-            grid: Vec::new(),
-            width: 0,
-            height: 0,
-        }
+        Day12 { grid: Grid::new() }
     }
 
-    // Up, Down, Left, Right
-    fn neighbors(&self, pos: (i32, i32)) -> Vec<Option<(i32, i32)>> {
-        let mut neighbors = Vec::new();
-        let (x, y) = pos;
-        if y > 0 {
-            neighbors.push(Some((x, y - 1)));
-        } else {
-            neighbors.push(None);
-        }
-        if y < self.height - 1 {
-            neighbors.push(Some((x, y + 1)));
-        } else {
-            neighbors.push(None);
-        }
-        if x > 0 {
-            neighbors.push(Some((x - 1, y)));
-        } else {
-            neighbors.push(None);
-        }
-        if x < self.width - 1 {
-            neighbors.push(Some((x + 1, y)));
-        } else {
-            neighbors.push(None);
+    fn regions(&self) -> Vec<Region> {
+        let mut regions = Vec::new();
+        let mut already_found: HashSet<(i32, i32)> = HashSet::new();
+        for ((x, y), &plant) in self.grid.iter() {
+            let pos = (x as i32, y as i32);
+            if !already_found.contains(&pos) {
+                let mut region = Region::new(plant);
+                self.find_region(pos, &mut region);
+                already_found.extend(&region.plots);
+                regions.push(region);
+            }
         }
-        neighbors
+        regions
     }
 
     fn find_region(&self, pos: (i32, i32), region: &mut Region) {
         let (x, y) = pos;
-        let plant = self.grid[y as usize][x as usize];
+        let plant = self.grid[(x as usize, y as usize)];
         if plant != region.plant {
             region.perimeter += 1;
             return;
         }
         region.plots.insert(pos);
 
-        for neighbor in self.neighbors(pos) {
-            if let Some(neighbor_pos) = neighbor {
-                if !region.plots.contains(&neighbor_pos) {
-                    self.find_region(neighbor_pos, region);
-                }
-            } else {
-                region.perimeter += 1;
+        // Sides on the edge of the map have no neighbour but still need fencing.
+        let mut inside = 0;
+        for ((nx, ny), _) in self.grid.neighbors((x as usize, y as usize)) {
+            inside += 1;
+            let neighbor_pos = (nx as i32, ny as i32);
+            if !region.plots.contains(&neighbor_pos) {
+                self.find_region(neighbor_pos, region);
             }
         }
+        region.perimeter += 4 - inside;
     }
 }
 
 impl Solution for Day12 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let lines = read_lines("./data/day12.txt")?;
-        self.grid
-            .extend(lines.flatten().map(|s| s.chars().collect::<Vec<char>>()));
-        self.height = self.grid.len() as i32;
-        self.width = if self.height > 0 {
-            self.grid[0].len()
-        } else {
-            0
-        } as i32;
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        let plant = |c: char| match c {
+            'A'..='Z' => Ok(c),
+            _ => Err(format!("invalid plant '{c}'")),
+        };
+        self.grid = Grid::parse(&read_to_string(input)?, plant)?;
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
-        let mut regions = Vec::new();
-        let mut already_found: HashSet<(i32, i32)> = HashSet::new();
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let pos = (x, y);
-                if !already_found.contains(&pos) {
-                    let mut region = Region::new(self.grid[y as usize][x as usize]);
-                    self.find_region(pos, &mut region);
-                    already_found.extend(&region.plots);
-                    regions.push(region);
-                }
-            }
-        }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("test_cases/2024/day12_01.txt", Answers::both(140, 80)),
+            Example::new("test_cases/2024/day12_02.txt", Answers::both(772, 436)),
+            Example::new("test_cases/2024/day12_03.txt", Answers::both(1930, 1206)),
+            Example::new("test_cases/2024/day12_04.txt", Answers::part2(236)),
+            Example::new("test_cases/2024/day12_05.txt", Answers::part2(368)),
+        ]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
         let mut total_cost = 0;
-        for region in &regions {
+        for region in &self.regions() {
             total_cost += (region.plots.len() as i32) * region.perimeter;
         }
+        Ok(total_cost.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
         let mut discount_cost = 0;
-        for region in &regions {
+        for region in &self.regions() {
             discount_cost += (region.plots.len() as i32) * region.count_sides();
         }
-        Ok(Answers::both(total_cost, discount_cost))
+        Ok(discount_cost.into())
     }
 }
 
@@ -219,12 +210,19 @@ impl Region {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day12::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 12)?)?;
         let answers = solution.solve()?;
         assert_eq!(answers, Answers::both(1467094, 881182));
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
@@ -1,8 +1,19 @@
-use crate::aoc::{read_lines, Answers, Solution};
+use crate::aoc::answer::Answer;
+use crate::aoc::error::{AocError, Line};
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_lines, Answers, Example, Input, Solution};
 use std::error::Error;
 
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 13,
+    title: "Claw Contraption",
+    tags: &[Tag::NumberTheory],
+    new: || Box::new(Day13::new()),
+};
+
 #[derive(Debug)]
-pub struct Day13 {
+struct Day13 {
     claw_machines: Vec<ClawMachine>,
 }
 
@@ -15,13 +26,14 @@ impl Day13 {
 }
 
 impl Solution for Day13 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let lines = read_lines("./data/day13.txt")?;
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
         let mut maybe_button_a: Option<Button> = None;
         let mut maybe_button_b: Option<Button> = None;
         let mut maybe_prize: Option<Prize> = None;
-        for line in lines.flatten() {
-            if line == "" {
+        for (i, text) in read_lines(input)?.enumerate() {
+            let text = text?;
+            let line = Line::new(i, &text);
+            if text == "" {
                 match (maybe_button_a, maybe_button_b, maybe_prize) {
                     (Some(button_a), Some(button_b), Some(prize)) => {
                         self.claw_machines.push(ClawMachine {
@@ -34,25 +46,29 @@ impl Solution for Day13 {
                         maybe_prize = None;
                     }
                     _ => {
-                        return Err("invalid claw machine".into());
+                        return Err(line.error(&text, "incomplete claw machine").into());
                     }
                 }
                 continue;
             }
 
-            let parts = line.split(": ").collect::<Vec<&str>>();
-            match parts[0] {
+            let (label, raw) = text
+                .split_once(": ")
+                .ok_or_else(|| line.error(&text, "expected a button or prize"))?;
+            match label {
                 "Button A" => {
-                    maybe_button_a = Some(Button::parse(parts[1])?);
+                    maybe_button_a = Some(Button::parse(&line, raw)?);
                 }
                 "Button B" => {
-                    maybe_button_b = Some(Button::parse(parts[1])?);
+                    maybe_button_b = Some(Button::parse(&line, raw)?);
                 }
                 "Prize" => {
-                    maybe_prize = Some(Prize::parse(parts[1])?);
+                    maybe_prize = Some(Prize::parse(&line, raw)?);
                 }
                 _ => {
-                    return Err("invalid line".into());
+                    return Err(line
+                        .error(label, format!("unknown label \"{label}\""))
+                        .into());
                 }
             }
         }
@@ -65,17 +81,27 @@ impl Solution for Day13 {
                 });
             }
             _ => {
-                return Err("invalid claw machine".into());
+                return Err(AocError::invalid("incomplete claw machine at the end").into());
             }
         }
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
-        let cost1 = compute_cost(&self.claw_machines);
-        rescale_prizes(&mut self.claw_machines);
-        let cost2 = compute_cost(&self.claw_machines);
-        Ok(Answers::both(cost1, cost2))
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day13_01.txt",
+            Answers::part1(480),
+        )]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        Ok(compute_cost(&self.claw_machines).into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let mut claw_machines = self.claw_machines.clone();
+        rescale_prizes(&mut claw_machines);
+        Ok(compute_cost(&claw_machines).into())
     }
 }
 
@@ -108,7 +134,7 @@ fn rescale_prizes(claw_machines: &mut Vec<ClawMachine>) {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct ClawMachine {
     button_a: Button,
     button_b: Button,
@@ -122,27 +148,26 @@ struct Button {
 }
 
 impl Button {
-    fn parse(raw: &str) -> Result<Button, Box<dyn Error>> {
+    fn parse(line: &Line, raw: &str) -> Result<Button, AocError> {
         let parts = raw.split(", ");
         let mut maybe_x: Option<i64> = None;
         let mut maybe_y: Option<i64> = None;
         for part in parts {
-            let subparts = part.split("+").collect::<Vec<&str>>();
-            match subparts[0] {
-                "X" => {
-                    maybe_x = Some(subparts[1].parse::<i64>()?);
+            match part.split_once("+") {
+                Some(("X", x)) => {
+                    maybe_x = Some(line.parse::<i64>(x)?);
                 }
-                "Y" => {
-                    maybe_y = Some(subparts[1].parse::<i64>()?);
+                Some(("Y", y)) => {
+                    maybe_y = Some(line.parse::<i64>(y)?);
                 }
                 _ => {
-                    return Err("invalid button".into());
+                    return Err(line.error(part, "invalid button"));
                 }
             }
         }
         match (maybe_x, maybe_y) {
             (Some(x), Some(y)) => Ok(Button { x, y }),
-            _ => Err("invalid button".into()),
+            _ => Err(line.error(raw, "invalid button")),
         }
     }
 }
@@ -154,27 +179,26 @@ struct Prize {
 }
 
 impl Prize {
-    fn parse(raw: &str) -> Result<Prize, Box<dyn Error>> {
+    fn parse(line: &Line, raw: &str) -> Result<Prize, AocError> {
         let parts = raw.split(", ");
         let mut maybe_x: Option<i64> = None;
         let mut maybe_y: Option<i64> = None;
         for part in parts {
-            let subparts = part.split("=").collect::<Vec<&str>>();
-            match subparts[0] {
-                "X" => {
-                    maybe_x = Some(subparts[1].parse::<i64>()?);
+            match part.split_once("=") {
+                Some(("X", x)) => {
+                    maybe_x = Some(line.parse::<i64>(x)?);
                 }
-                "Y" => {
-                    maybe_y = Some(subparts[1].parse::<i64>()?);
+                Some(("Y", y)) => {
+                    maybe_y = Some(line.parse::<i64>(y)?);
                 }
                 _ => {
-                    return Err("invalid prize".into());
+                    return Err(line.error(part, "invalid prize"));
                 }
             }
         }
         match (maybe_x, maybe_y) {
             (Some(x), Some(y)) => Ok(Prize { x, y }),
-            _ => Err("invalid prize".into()),
+            _ => Err(line.error(raw, "invalid prize")),
         }
     }
 }
@@ -321,12 +345,19 @@ fn handle_det_zero(a1: i64, b1: i64, a2: i64, b2: i64, c1: i64, c2: i64) -> Diop
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day13::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 13)?)?;
         let answers = solution.solve()?;
-        assert_eq!(answers, Answers::both(29187i64, 99968222587852i64));
+        assert_eq!(answers, Answers::both(29187, 99968222587852i64));
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
@@ -0,0 +1,194 @@
+use crate::aoc::answer::Answer;
+use crate::aoc::cancel;
+use crate::aoc::error::{AocError, Line};
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_lines, Answers, Example, Input, Mode, Solution};
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::hash::Hash;
+
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 14,
+    title: "Restroom Redoubt",
+    tags: &[Tag::Grid, Tag::Simulation, Tag::NumberTheory],
+    new: || Box::new(Day14::new()),
+};
+
+#[derive(Debug)]
+struct Day14 {
+    robots: Vec<Robot>,
+    dim: (i32, i32),
+}
+
+impl Day14 {
+    pub fn new() -> Day14 {
+        Day14 {
+            robots: Vec::new(),
+            dim: (101, 103),
+        }
+    }
+}
+
+impl Solution for Day14 {
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        for (i, text) in read_lines(input)?.enumerate() {
+            let text = text?;
+            let line = Line::new(i, &text);
+            let (pos, vel) = text
+                .split_once(' ')
+                .ok_or_else(|| line.missing("a velocity"))?;
+            let pos = parse_vector(&line, pos, "p")?;
+            let vel = parse_vector(&line, vel, "v")?;
+            self.robots.push(Robot { pos, vel });
+        }
+        Ok(())
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let dim = self.dim;
+        let mut count_by_quadrant: HashMap<Quadrant, i32> = HashMap::new();
+        for final_pos in self.robots.iter().map(|r| r.final_pos(100, dim)) {
+            let quad = Quadrant::get(final_pos, dim);
+            if let Some(count) = count_by_quadrant.get(&quad) {
+                count_by_quadrant.insert(quad, count + 1);
+            } else {
+                count_by_quadrant.insert(quad, 1);
+            }
+        }
+        let mut safety_score = 1;
+        for (q, c) in &count_by_quadrant {
+            safety_score *= match q {
+                Quadrant::None => 1,
+                _ => *c,
+            }
+        }
+        Ok(safety_score.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let dim = self.dim;
+        let (w, h) = dim;
+        // Every robot is back where it started after w * h seconds, so the search can stop there.
+        for num_seconds in 0..w * h {
+            cancel::check()?;
+            if no_dupes(self.robots.iter().map(|r| r.final_pos(num_seconds, dim))) {
+                return Ok(num_seconds.into());
+            }
+        }
+        Err(AocError::unsolvable("the robots never form a picture").into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day14_01.txt",
+            Answers::part1(12),
+        )]
+    }
+
+    fn set_mode(&mut self, mode: Mode) {
+        self.dim = match mode {
+            Mode::Live => (101, 103),
+            Mode::Example => (11, 7),
+        };
+    }
+}
+
+/// Parse a position or velocity like `p=0,4`, where `name` is the part before the `=`.
+fn parse_vector(line: &Line, raw: &str, name: &str) -> Result<(i32, i32), AocError> {
+    match raw.split_once('=') {
+        Some((prefix, vector)) if prefix == name => {
+            let (x, y) = vector
+                .split_once(',')
+                .ok_or_else(|| line.error(vector, format!("expected {name}=x,y")))?;
+            Ok((line.parse::<i32>(x)?, line.parse::<i32>(y)?))
+        }
+        _ => Err(line.error(raw, format!("expected {name}=x,y"))),
+    }
+}
+
+#[derive(Debug)]
+struct Robot {
+    pos: (i32, i32),
+    vel: (i32, i32),
+}
+
+impl Robot {
+    fn final_pos(&self, t: i32, dim: (i32, i32)) -> (i32, i32) {
+        let (w, h) = dim;
+        let (px, py) = self.pos;
+        let (vx, vy) = self.vel;
+        ((px + vx * t).rem_euclid(w), (py + vy * t).rem_euclid(h))
+    }
+}
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+enum Quadrant {
+    First,
+    Second,
+    Third,
+    Fourth,
+    None,
+}
+
+impl Quadrant {
+    fn get(pos: (i32, i32), dim: (i32, i32)) -> Quadrant {
+        let (x, y) = pos;
+        let (w, h) = dim;
+        if x < w / 2 && y < h / 2 {
+            Self::First
+        } else if x > w / 2 && y < h / 2 {
+            Self::Second
+        } else if x < w / 2 && y > h / 2 {
+            Self::Third
+        } else if x > w / 2 && y > h / 2 {
+            Self::Fourth
+        } else {
+            Self::None
+        }
+    }
+}
+
+fn no_dupes<T>(iter: T) -> bool
+where
+    T: IntoIterator,
+    T::Item: Eq + Hash,
+{
+    let mut uniq = HashSet::new();
+    iter.into_iter().all(move |x| uniq.insert(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::{check_examples, default_input};
+    #[test]
+    #[ignore = "needs the private input"]
+    fn solution() -> Result<(), Box<dyn Error>> {
+        let mut solution = Day14::new();
+        solution.parse_input(&default_input(2024, 14)?)?;
+        let answers = solution.solve()?;
+        assert_eq!(answers, Answers::both(214109808, 7687));
+        Ok(())
+    }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
+
+    #[test]
+    fn no_picture() {
+        let robot = || Robot {
+            pos: (0, 0),
+            vel: (1, 2),
+        };
+        let mut solution = Day14::new();
+        solution.robots = vec![robot(), robot()];
+        let error = solution.part2().unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<AocError>(),
+            Some(AocError::Unsolvable(_))
+        ));
+    }
+}
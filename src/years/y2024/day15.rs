@@ -0,0 +1,397 @@
+use crate::aoc::answer::Answer;
+use crate::aoc::error::AocError;
+use crate::aoc::grid::Grid;
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_to_string, Answers, Example, Input, Solution};
+use std::error::Error;
+use std::fmt::Display;
+
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 15,
+    title: "Warehouse Woes",
+    tags: &[Tag::Grid, Tag::Simulation],
+    new: || Box::new(Day15::new()),
+};
+
+#[derive(Debug)]
+struct Day15 {
+    robot: Robot,
+    grid: Grid<Cell>,
+}
+
+impl Day15 {
+    pub fn new() -> Day15 {
+        Day15 {
+            robot: Robot::new(),
+            grid: Grid::new(),
+        }
+    }
+
+    fn next_pos(&self, pos: Position, dir: Direction) -> Option<Position> {
+        let (x, y) = pos;
+        match dir {
+            Direction::Up => {
+                if y >= 1 {
+                    Some((x, y - 1))
+                } else {
+                    None
+                }
+            }
+            Direction::Down => {
+                if y + 1 < self.grid.height {
+                    Some((x, y + 1))
+                } else {
+                    None
+                }
+            }
+            Direction::Left => {
+                if x >= 1 {
+                    Some((x - 1, y))
+                } else {
+                    None
+                }
+            }
+            Direction::Right => {
+                if x + 1 < self.grid.width {
+                    Some((x + 1, y))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// The position one step from `pos`, for a box being pushed there. Warehouses are walled in,
+    /// so a box can only be pushed off the edge if the wall is missing.
+    fn push_pos(&self, pos: Position, dir: Direction) -> Result<Position, AocError> {
+        self.next_pos(pos, dir).ok_or_else(|| {
+            AocError::invalid(format!(
+                "box at {pos:?} pushed off the edge of the {}x{} warehouse",
+                self.grid.width, self.grid.height
+            ))
+        })
+    }
+
+    /// Make every move starting from the given warehouse and robot position and return the sum
+    /// of the box coordinates. The parsed warehouse is restored afterwards.
+    fn simulate(&mut self, grid: Grid<Cell>, pos: Position) -> Result<usize, AocError> {
+        let grid = std::mem::replace(&mut self.grid, grid);
+        let pos = std::mem::replace(&mut self.robot.pos, pos);
+        self.robot.move_num = 0;
+        let result = loop {
+            match self.do_move() {
+                Some(Ok(_)) => (),
+                Some(Err(error)) => break Err(error),
+                None => break Ok(self.compute_sum()),
+            }
+        };
+        (self.grid, self.robot.pos) = (grid, pos);
+        result
+    }
+
+    fn compute_sum(&self) -> usize {
+        self.grid
+            .enumerate_where(|cell| matches!(cell, Cell::Box | Cell::BoxLeft))
+            .map(|((x, y), _)| 100 * y + x)
+            .sum()
+    }
+
+    /// The warehouse twice as wide, with every box taking up two cells.
+    fn expanded(&self) -> Result<Grid<Cell>, String> {
+        let wide = |cell: &Cell| matches!(cell, Cell::BoxLeft | Cell::BoxRight);
+        if self.grid.enumerate_where(wide).next().is_some() {
+            return Err("cannot expand already expanded map".to_string());
+        }
+        Ok(self.grid.scale_x(|cell| match cell {
+            Cell::Box => [Cell::BoxLeft, Cell::BoxRight],
+            &cell => [cell, cell],
+        }))
+    }
+
+    /// Make the next move, returning `None` once there are no moves left.
+    fn do_move(&mut self) -> Option<Result<Position, AocError>> {
+        if self.robot.move_num >= self.robot.moves.len() {
+            return None;
+        }
+        let dir = self.robot.moves[self.robot.move_num];
+        self.robot.move_num += 1;
+        Some(self.move_robot(dir).map(|_| self.robot.pos))
+    }
+
+    fn move_robot(&mut self, dir: Direction) -> Result<(), AocError> {
+        let Some(next_pos) = self.next_pos(self.robot.pos, dir) else {
+            return Ok(());
+        };
+        let moved = match self.grid[next_pos] {
+            Cell::Empty => true,
+            Cell::Box => self.push_small_box(dir, next_pos)?,
+            Cell::BoxLeft | Cell::BoxRight => {
+                let can_push = self.can_push_large_box(dir, next_pos)?;
+                if can_push {
+                    self.push_large_box(dir, next_pos)?;
+                }
+                can_push
+            }
+            Cell::Wall => false,
+        };
+        if moved {
+            self.robot.pos = next_pos;
+        }
+        Ok(())
+    }
+
+    fn push_small_box(&mut self, dir: Direction, pos: Position) -> Result<bool, AocError> {
+        let mut next_pos = pos;
+        while let Cell::Box = self.grid[next_pos] {
+            next_pos = self.push_pos(next_pos, dir)?;
+        }
+        if let Cell::Empty = self.grid[next_pos] {
+            self.grid[next_pos] = Cell::Box;
+            self.grid[pos] = Cell::Empty;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn can_push_large_box(&mut self, dir: Direction, pos: Position) -> Result<bool, AocError> {
+        let (x, y) = pos;
+        if let Direction::Up | Direction::Down = dir {
+            let (next_pos_left, next_pos_right) = match self.grid[(x, y)] {
+                Cell::BoxLeft => (self.push_pos(pos, dir)?, self.push_pos(((x + 1), y), dir)?),
+                Cell::BoxRight => (self.push_pos(((x - 1), y), dir)?, self.push_pos(pos, dir)?),
+                _ => {
+                    return Ok(false);
+                }
+            };
+            let (xl, yl) = next_pos_left;
+            let (xr, yr) = next_pos_right;
+            match (self.grid[(xl, yl)], self.grid[(xr, yr)]) {
+                (Cell::Empty, Cell::Empty) => Ok(true),
+                (Cell::BoxRight, Cell::Empty) => self.can_push_large_box(dir, (xl, yl)),
+                (Cell::Empty, Cell::BoxLeft) => self.can_push_large_box(dir, (xr, yr)),
+                (Cell::BoxLeft, Cell::BoxRight) => self.can_push_large_box(dir, (xl, yl)),
+                (Cell::BoxRight, Cell::BoxLeft) => {
+                    let left_flag = self.can_push_large_box(dir, (xl, yl))?;
+                    let right_flag = self.can_push_large_box(dir, (xr, yr))?;
+                    Ok(left_flag && right_flag)
+                }
+                _ => Ok(false),
+            }
+        } else {
+            match self.grid[(x, y)] {
+                Cell::BoxLeft | Cell::BoxRight => {
+                    let (xn, yn) = self.push_pos(pos, dir)?;
+                    match self.grid[(xn, yn)] {
+                        Cell::Empty => Ok(true),
+                        Cell::BoxLeft | Cell::BoxRight => self.can_push_large_box(dir, (xn, yn)),
+                        _ => Ok(false),
+                    }
+                }
+                _ => Ok(false),
+            }
+        }
+    }
+
+    fn push_large_box(&mut self, dir: Direction, pos: Position) -> Result<(), AocError> {
+        let (x, y) = pos;
+        if let Direction::Up | Direction::Down = dir {
+            let (next_pos_left, next_pos_right, offset_x) = match self.grid[(x, y)] {
+                Cell::BoxLeft => (
+                    self.push_pos(pos, dir)?,
+                    self.push_pos(((x + 1), y), dir)?,
+                    x + 1,
+                ),
+                Cell::BoxRight => (
+                    self.push_pos(((x - 1), y), dir)?,
+                    self.push_pos(pos, dir)?,
+                    x - 1,
+                ),
+                _ => {
+                    return Ok(());
+                }
+            };
+            let (xl, yl) = next_pos_left;
+            let (xr, yr) = next_pos_right;
+            match (self.grid[(xl, yl)], self.grid[(xr, yr)]) {
+                (Cell::BoxRight, Cell::Empty) => self.push_large_box(dir, (xl, yl))?,
+                (Cell::Empty, Cell::BoxLeft) => self.push_large_box(dir, (xr, yr))?,
+
+                (Cell::BoxLeft, Cell::BoxRight) => self.push_large_box(dir, (xl, yl))?,
+                (Cell::BoxRight, Cell::BoxLeft) => {
+                    self.push_large_box(dir, (xl, yl))?;
+                    self.push_large_box(dir, (xr, yr))?;
+                }
+                _ => (),
+            }
+            self.grid[(xl, yl)] = Cell::BoxLeft;
+            self.grid[(xr, yr)] = Cell::BoxRight;
+            self.grid[(x, y)] = Cell::Empty;
+            self.grid[(offset_x, y)] = Cell::Empty;
+        } else if let Cell::BoxLeft | Cell::BoxRight = self.grid[(x, y)] {
+            let (xn, yn) = self.push_pos(pos, dir)?;
+            if let Cell::BoxLeft | Cell::BoxRight = self.grid[(xn, yn)] {
+                self.push_large_box(dir, (xn, yn))?;
+            }
+            self.grid[(xn, yn)] = self.grid[(x, y)];
+            self.grid[(x, y)] = Cell::Empty;
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day15 {
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        let text = read_to_string(input)?;
+        let (map, moves) = text.split_once("\n\n").unwrap_or((&text, ""));
+        let (grid, markers) = Grid::parse_with_markers(map, "@", Cell::parse)?;
+        self.robot.pos = markers.one('@')?;
+        // The moves start after the map and the blank line following it.
+        let first_line = grid.height + 2;
+        self.grid = grid;
+        for (y, line) in moves.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let direction =
+                    Direction::parse(c).map_err(|e| AocError::parse(first_line + y, x + 1, e))?;
+                self.robot.moves.push(direction);
+            }
+        }
+        Ok(())
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let sum = self.simulate(self.grid.clone(), self.robot.pos)?;
+        Ok(sum.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let (x, y) = self.robot.pos;
+        let sum = self.simulate(self.expanded()?, (2 * x, y))?;
+        Ok(sum.into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("test_cases/2024/day15_01.txt", Answers::both(1321, 836)),
+            Example::new("test_cases/2024/day15_02.txt", Answers::both(1215, 1327)),
+            Example::new("test_cases/2024/day15_03.txt", Answers::both(10092, 9021)),
+            Example::new("test_cases/2024/day15_04.txt", Answers::part1(2028)),
+        ]
+    }
+}
+
+impl Display for Day15 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.grid
+                .rows()
+                .enumerate()
+                .map(|(y, r)| r
+                    .iter()
+                    .enumerate()
+                    .map(|(x, c)| if (x, y) == self.robot.pos {
+                        "@".to_string()
+                    } else {
+                        format!("{c}")
+                    })
+                    .collect::<String>())
+                .collect::<Vec<String>>()
+                .join("\n")
+        )
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Cell {
+    Wall,
+    Box,
+    BoxLeft,
+    BoxRight,
+    Empty,
+}
+
+impl Cell {
+    fn parse(c: char) -> Result<Self, String> {
+        match c {
+            '.' => Ok(Self::Empty),
+            '#' => Ok(Self::Wall),
+            'O' => Ok(Self::Box),
+            '@' => Ok(Self::Empty),
+            _ => Err(format!("invalid character \"{c}\" in map")),
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Cell::Wall => write!(f, "#"),
+            Cell::Box => write!(f, "O"),
+            Cell::BoxLeft => write!(f, "["),
+            Cell::BoxRight => write!(f, "]"),
+            Cell::Empty => write!(f, "."),
+        }
+    }
+}
+
+type Position = (usize, usize);
+
+#[derive(Copy, Clone, Debug)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn parse(c: char) -> Result<Self, String> {
+        match c {
+            '^' => Ok(Self::Up),
+            'v' => Ok(Self::Down),
+            '<' => Ok(Self::Left),
+            '>' => Ok(Self::Right),
+            _ => Err(format!("invalid character \"{c}\" in moves")),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Robot {
+    pos: Position,
+    moves: Vec<Direction>,
+    move_num: usize,
+}
+
+impl Robot {
+    fn new() -> Robot {
+        Robot {
+            pos: (0, 0),
+            moves: Vec::new(),
+            move_num: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::{check_examples, default_input};
+    #[test]
+    #[ignore = "needs the private input"]
+    fn solution() -> Result<(), Box<dyn Error>> {
+        let mut solution = Day15::new();
+        solution.parse_input(&default_input(2024, 15)?)?;
+        let answers = solution.solve()?;
+        assert_eq!(answers, Answers::both(1478649, 1495455));
+        Ok(())
+    }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
+}
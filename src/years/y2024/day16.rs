@@ -1,27 +1,44 @@
-use crate::aoc::{read_lines, Answers, Direction, Position, Solution};
+use crate::aoc::answer::Answer;
+use crate::aoc::grid::Grid;
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_to_string, Answers, Direction, Example, Input, Position, Solution};
 use std::cmp::Reverse;
 use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::error::Error;
 use std::fmt::Display;
 
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 16,
+    title: "Reindeer Maze",
+    tags: &[Tag::Grid, Tag::Graph],
+    new: || Box::new(Day16::new()),
+};
+
 #[derive(Debug)]
-pub struct Day16 {
-    width: usize,
-    height: usize,
-    grid: Vec<Vec<Cell>>,
+struct Day16 {
+    grid: Grid<Cell>,
     start: Position,
     end: Position,
+    /// The result of the best path search, shared by both parts.
+    paths: Option<Paths>,
 }
 
 impl Day16 {
     pub fn new() -> Day16 {
         Day16 {
-            width: 0,
-            height: 0,
-            grid: Vec::new(),
+            grid: Grid::new(),
             start: (0, 0),
             end: (0, 0),
+            paths: None,
+        }
+    }
+
+    fn paths(&mut self) -> &Paths {
+        if self.paths.is_none() {
+            self.paths = Some(self.minimal_paths());
         }
+        self.paths.as_ref().unwrap()
     }
 
     fn next(&self, pos: Position, dir: Direction) -> Option<(Position, Cell)> {
@@ -29,28 +46,28 @@ impl Day16 {
         match dir {
             Direction::Up => {
                 if y >= 1 {
-                    Some(((x, y - 1), self.grid[y - 1][x]))
+                    Some(((x, y - 1), self.grid[(x, y - 1)]))
                 } else {
                     None
                 }
             }
             Direction::Down => {
-                if y + 1 < self.height {
-                    Some(((x, y + 1), self.grid[y + 1][x]))
+                if y + 1 < self.grid.height {
+                    Some(((x, y + 1), self.grid[(x, y + 1)]))
                 } else {
                     None
                 }
             }
             Direction::Left => {
                 if x >= 1 {
-                    Some(((x - 1, y), self.grid[y][x - 1]))
+                    Some(((x - 1, y), self.grid[(x - 1, y)]))
                 } else {
                     None
                 }
             }
             Direction::Right => {
-                if x + 1 < self.width {
-                    Some(((x + 1, y), self.grid[y][x + 1]))
+                if x + 1 < self.grid.width {
+                    Some(((x + 1, y), self.grid[(x + 1, y)]))
                 } else {
                     None
                 }
@@ -58,7 +75,7 @@ impl Day16 {
         }
     }
 
-    fn minimal_paths(&self) -> (usize, usize) {
+    fn minimal_paths(&self) -> Paths {
         let mut visited = HashSet::new();
         let mut binary_heap =
             BinaryHeap::from([(Reverse(0 as usize), self.start, Direction::Right, None)]);
@@ -103,64 +120,75 @@ impl Day16 {
             }
         }
 
+        Paths {
+            low_score,
+            end_dirs,
+            low_scoring_prevs,
+        }
+    }
+}
+
+/// The best score to the end, the directions it can be reached in with that score and, for each
+/// position and direction, the steps before it on a best path there.
+#[derive(Debug)]
+struct Paths {
+    low_score: usize,
+    end_dirs: Vec<Direction>,
+    low_scoring_prevs: HashMap<(Position, Direction), HashSet<(Position, Direction)>>,
+}
+
+impl Paths {
+    /// The number of seats on any of the best paths, found by walking back from the end.
+    fn num_seats(&self, end: Position) -> usize {
         let mut nodes = HashSet::new();
         let mut visited = HashSet::new();
-        let mut stack = end_dirs
+        let mut stack = self
+            .end_dirs
             .iter()
-            .map(|d| (self.end, *d))
+            .map(|d| (end, *d))
             .collect::<Vec<(Position, Direction)>>();
         while let Some((pos, dir)) = stack.pop() {
             nodes.insert(pos);
             if visited.insert((pos, dir)) {
-                let empty = HashSet::new();
-                let prevs = low_scoring_prevs.get(&(pos, dir)).unwrap_or(&empty);
-                stack.extend(prevs);
+                if let Some(prevs) = self.low_scoring_prevs.get(&(pos, dir)) {
+                    stack.extend(prevs);
+                }
             }
         }
-
-        (low_score, nodes.len())
+        nodes.len()
     }
 }
 
 impl Solution for Day16 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let lines = read_lines("./data/day16.txt")?;
-        for (y, line) in lines.flatten().enumerate() {
-            let chars = line.chars().collect::<Vec<char>>();
-            self.grid.push(
-                chars
-                    .iter()
-                    .map(|&c| Cell::parse(c))
-                    .collect::<Result<_, _>>()?,
-            );
-            for (x, &c) in chars.iter().enumerate() {
-                if c == 'S' {
-                    self.start = (x, y);
-                }
-                if c == 'E' {
-                    self.end = (x, y);
-                }
-            }
-        }
-        self.height = self.grid.len();
-        self.width = if self.height > 0 {
-            self.grid[0].len()
-        } else {
-            0
-        };
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        let (grid, markers) = Grid::parse_with_markers(&read_to_string(input)?, "SE", Cell::parse)?;
+        self.grid = grid;
+        self.start = markers.one('S')?;
+        self.end = markers.one('E')?;
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
-        let (score, num_seats) = self.minimal_paths();
-        Ok(Answers::both(score, num_seats))
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("test_cases/2024/day16_01.txt", Answers::both(7036, 45)),
+            Example::new("test_cases/2024/day16_02.txt", Answers::both(11048, 64)),
+        ]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        Ok(self.paths().low_score.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let end = self.end;
+        Ok(self.paths().num_seats(end).into())
     }
 }
 
 // For debugging
 #[allow(dead_code)]
 fn print_map(map: &Day16, nodes: &HashSet<Position>) {
-    for (y, row) in map.grid.iter().enumerate() {
+    for (y, row) in map.grid.rows().enumerate() {
         println!(
             "{}",
             row.iter()
@@ -190,7 +218,7 @@ impl Cell {
         match c {
             '#' => Ok(Cell::Wall),
             '.' | 'S' | 'E' => Ok(Cell::Empty),
-            _ => Err("invalid character".to_string()),
+            _ => Err(format!("invalid character \"{c}\"")),
         }
     }
 }
@@ -206,12 +234,19 @@ impl Display for Cell {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day16::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 16)?)?;
         let answers = solution.solve()?;
-        assert_eq!(answers, Answers::both(109496usize, 551usize));
+        assert_eq!(answers, Answers::both(109496, 551));
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
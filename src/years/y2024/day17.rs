@@ -1,9 +1,19 @@
-use crate::aoc::{read_lines, Answers, Solution};
+use crate::aoc::answer::Answer;
+use crate::aoc::error::{AocError, Line};
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_lines, Answers, Example, Input, Solution};
 use std::error::Error;
-use std::num::ParseIntError;
+
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 17,
+    title: "Chronospatial Computer",
+    tags: &[Tag::Simulation],
+    new: || Box::new(Day17::new()),
+};
 
 #[derive(Debug)]
-pub struct Day17 {
+struct Day17 {
     computer: Computer,
 }
 
@@ -16,43 +26,58 @@ impl Day17 {
 }
 
 impl Solution for Day17 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let lines = read_lines("./data/day17.txt")?;
-        for line in lines.flatten() {
-            if line == "" {
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        for (i, text) in read_lines(input)?.enumerate() {
+            let text = text?;
+            let line = Line::new(i, &text);
+            if text == "" {
                 continue;
             }
-            let parts = line.split(": ").collect::<Vec<&str>>();
-            if parts.len() != 2 {
-                return Err("invalid line".into());
-            }
-            match parts[0] {
-                "Register A" => self.computer.a = parts[1].parse::<u128>()?,
-                "Register B" => self.computer.b = parts[1].parse::<u128>()?,
-                "Register C" => self.computer.c = parts[1].parse::<u128>()?,
+            let (label, value) = text
+                .split_once(": ")
+                .ok_or_else(|| line.error(&text, "expected a register or program"))?;
+            match label {
+                "Register A" => self.computer.a = line.parse::<u128>(value)?,
+                "Register B" => self.computer.b = line.parse::<u128>(value)?,
+                "Register C" => self.computer.c = line.parse::<u128>(value)?,
                 "Program" => {
-                    self.computer.instructions = parts[1]
+                    self.computer.instructions = value
                         .split(",")
-                        .map(|i| i.parse::<u8>())
-                        .collect::<Result<Vec<u8>, ParseIntError>>()?
+                        .map(|i| line.parse::<u8>(i))
+                        .collect::<Result<Vec<u8>, _>>()?
                 }
                 _ => {
-                    return Err("invalid line".into());
+                    return Err(line
+                        .error(label, format!("unknown label \"{label}\""))
+                        .into());
                 }
             }
         }
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
-        let output = self.computer.run()?;
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(
+                "test_cases/2024/day17_01.txt",
+                Answers::part1("4,6,3,5,6,3,5,2,1,0"),
+            ),
+            Example::new("test_cases/2024/day17_02.txt", Answers::part2(117440)),
+        ]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let output = self.computer.clone().run()?;
         let output_str = output
             .iter()
             .map(|x| x.to_string())
             .collect::<Vec<_>>()
             .join(",");
-        let quine_a = backtrack(&self.computer)?;
-        Ok(Answers::both(output_str, quine_a))
+        Ok(output_str.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        Ok(backtrack(&self.computer)?.into())
     }
 }
 
@@ -66,13 +91,14 @@ impl Solution for Day17 {
 // Register A must encode the instructions as outputs in some way, thus we must consume 3 bits of
 // register A during each iteration of the loop. If we know the final value of A after the
 // computation, we can try every 3 bit suffix for that value until we find the one that outputs the
-// previous value in the instructions.
-fn backtrack(computer: &Computer) -> Result<u128, String> {
+// previous value in the instructions. Several values of A can output the whole program, e.g. when
+// the low bits of the initial value are never output, so the smallest one found is the answer.
+fn backtrack(computer: &Computer) -> Result<u128, Box<dyn Error>> {
     let mut no_jump = computer.clone();
     no_jump.instructions.pop();
     no_jump.instructions.pop();
     let mut stack = vec![(0, computer.instructions.len() - 1)];
-    let mut final_a = 0;
+    let mut final_a = None;
     while let Some((a, i)) = stack.pop() {
         for suffix in 0..8 {
             let test_a = (a << 3) | suffix;
@@ -81,14 +107,14 @@ fn backtrack(computer: &Computer) -> Result<u128, String> {
             let output = no_jump.run()?[0];
             if output == computer.instructions[i] {
                 if i == 0 {
-                    final_a = test_a;
+                    final_a = Some(final_a.map_or(test_a, |a: u128| a.min(test_a)));
                 } else {
                     stack.push((test_a, i - 1));
                 }
             }
         }
     }
-    Ok(final_a)
+    final_a.ok_or_else(|| AocError::unsolvable("no value of register A outputs the program").into())
 }
 
 #[derive(Clone, Debug)]
@@ -173,10 +199,12 @@ impl Computer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day17::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 17)?)?;
         let answers = solution.solve()?;
         assert_eq!(
             answers,
@@ -184,4 +212,9 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
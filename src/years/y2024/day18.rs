@@ -1,10 +1,22 @@
+use crate::aoc::answer::Answer;
+use crate::aoc::cancel;
+use crate::aoc::error::{AocError, Line};
 use crate::aoc::grid::Grid;
-use crate::aoc::{read_lines, Answers, Map, Position, Solution};
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_lines, Answers, Example, Input, Map, Mode, Position, Solution};
 use std::error::Error;
 use std::fmt::Display;
 
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 18,
+    title: "RAM Run",
+    tags: &[Tag::Grid, Tag::Graph],
+    new: || Box::new(Day18::new()),
+};
+
 #[derive(Debug)]
-pub struct Day18 {
+struct Day18 {
     grid: Grid<Cell>,
     bytes: Vec<Position>,
     num_bytes: usize,
@@ -16,62 +28,88 @@ impl Day18 {
         Day18 {
             grid: Grid::new(),
             bytes: Vec::new(),
-            num_bytes: 0,
-            size: 0,
+            num_bytes: 1024,
+            size: 71,
+        }
+    }
+
+    /// Reset the grid to just the first `num_bytes` bytes having fallen.
+    fn corrupt_first(&mut self) {
+        self.grid = Grid::fill(Cell::Safe, self.size, self.size);
+        for i in 0..self.num_bytes {
+            let byte = self.bytes[i];
+            self.grid[byte] = Cell::Corrupted;
         }
     }
 }
 
 impl Solution for Day18 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let filename = "./data/day18.txt";
-        self.num_bytes = if filename.contains("/data/") {
-            1024
-        } else if filename.contains("/examples/") {
-            12
-        } else {
-            return Err("expected path to contain \"/data/\" or \"/examples/\"".into());
-        };
-        self.size = if self.num_bytes == 1024 { 71 } else { 7 };
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
         self.grid = Grid::fill(Cell::Safe, self.size, self.size);
 
-        let lines = read_lines(filename)?;
-        for (line_num, line) in lines.flatten().enumerate() {
-            let parts = line
-                .split(",")
-                .map(|p| p.parse::<usize>())
-                .collect::<Result<Vec<_>, _>>()?;
-            if parts.len() != 2 {
-                return Err(format!("invalid line {line_num}").into());
-            }
-            self.bytes.push((parts[0], parts[1]));
+        for (i, text) in read_lines(input)?.enumerate() {
+            let text = text?;
+            let line = Line::new(i, &text);
+            let (x, y) = text
+                .split_once(",")
+                .ok_or_else(|| line.missing("a second coordinate"))?;
+            let coord = |token| {
+                let value = line.parse::<usize>(token)?;
+                if value >= self.size {
+                    let message =
+                        format!("byte {text} is outside the {0}x{0} memory space", self.size);
+                    return Err(line.invalid(token, message));
+                }
+                Ok(value)
+            };
+            self.bytes.push((coord(x)?, coord(y)?));
         }
         if self.bytes.len() < self.num_bytes {
-            return Err(format!("byte stream has less than {} bytes", self.num_bytes).into());
+            let message = format!("byte stream has less than {} bytes", self.num_bytes);
+            return Err(AocError::invalid(message).into());
         }
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
-        for i in 0..self.num_bytes {
-            let byte = self.bytes[i];
-            self.grid[byte] = Cell::Corrupted;
-        }
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        self.corrupt_first();
         let start = (0, 0);
         let end = (self.size - 1, self.size - 1);
         let (dist, _) = self.pathfind(Cell::Safe, start, end);
-        let mut byte_str = String::new();
+        Ok(dist
+            .ok_or_else(|| AocError::unsolvable("no path to the exit"))?
+            .into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        self.corrupt_first();
+        let start = (0, 0);
+        let end = (self.size - 1, self.size - 1);
         for i in self.num_bytes..self.bytes.len() {
+            cancel::check()?;
             let byte = self.bytes[i];
             self.grid[byte] = Cell::Corrupted;
             if let Some(true) = self.chokepoint(byte) {
                 if let (None, _) = self.pathfind(Cell::Safe, start, end) {
-                    byte_str = format!("{},{}", byte.0, byte.1);
-                    break;
+                    return Ok(format!("{},{}", byte.0, byte.1).into());
                 }
             }
         }
-        Ok(Answers::both(dist.unwrap(), byte_str))
+        Err(AocError::unsolvable("no byte cuts off the exit").into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day18_01.txt",
+            Answers::both(22, "6,1"),
+        )]
+    }
+
+    fn set_mode(&mut self, mode: Mode) {
+        (self.num_bytes, self.size) = match mode {
+            Mode::Live => (1024, 71),
+            Mode::Example => (12, 7),
+        };
     }
 }
 
@@ -193,12 +231,19 @@ impl Display for Cell {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day18::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 18)?)?;
         let answers = solution.solve()?;
-        assert_eq!(answers, Answers::both(372usize, "25,6"));
+        assert_eq!(answers, Answers::both(372, "25,6"));
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
@@ -1,10 +1,20 @@
-use crate::aoc::{Answers, Solution};
+use crate::aoc::answer::Answer;
+use crate::aoc::error::AocError;
+use crate::aoc::puzzle::Puzzle;
+use crate::aoc::{read_to_string, Answers, Example, Input, Solution};
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::error::Error;
-use std::fs::read_to_string;
+
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 19,
+    title: "Linen Layout",
+    tags: &[],
+    new: || Box::new(Day19::new()),
+};
 
 #[derive(Debug)]
-pub struct Day19 {
+struct Day19 {
     available: FxHashSet<String>,
     patterns: Vec<String>,
 }
@@ -19,11 +29,12 @@ impl Day19 {
 }
 
 impl Solution for Day19 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let raw = read_to_string("./data/day19.txt")?;
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        let raw = read_to_string(input)?;
         let parts = raw.split("\n\n").collect::<Vec<_>>();
         if parts.len() != 2 {
-            return Err("invalid input".into());
+            let message = "expected towels and designs separated by a blank line";
+            return Err(AocError::invalid(message).into());
         }
         self.available
             .extend(parts[0].split(", ").map(|t| t.to_string()));
@@ -36,18 +47,31 @@ impl Solution for Day19 {
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day19_01.txt",
+            Answers::both(6, 16),
+        )]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
         let mut num_possible = 0;
-        let mut num_ways = 0;
         for pattern in &self.patterns {
             let mut memos = FxHashMap::default();
-            let recipes = count_recipes(pattern, &self.available, &mut memos);
-            if recipes > 0 {
+            if count_recipes(pattern, &self.available, &mut memos) > 0 {
                 num_possible += 1;
             }
-            num_ways += recipes;
         }
-        Ok(Answers::both(num_possible, num_ways))
+        Ok(num_possible.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let mut num_ways = 0;
+        for pattern in &self.patterns {
+            let mut memos = FxHashMap::default();
+            num_ways += count_recipes(pattern, &self.available, &mut memos);
+        }
+        Ok(num_ways.into())
     }
 }
 
@@ -80,12 +104,19 @@ fn count_recipes(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day19::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 19)?)?;
         let answers = solution.solve()?;
         assert_eq!(answers, Answers::both(228, 584553405070389usize));
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
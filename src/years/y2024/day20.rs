@@ -1,13 +1,24 @@
+use crate::aoc::answer::Answer;
+use crate::aoc::error::AocError;
 use crate::aoc::grid::Grid;
-use crate::aoc::{read_lines, Answers, Map, Position, Solution};
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_to_string, Answers, Example, Input, Map, Mode, Position, Solution};
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::cmp::min;
 use std::error::Error;
 use std::fmt::Display;
 
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 20,
+    title: "Race Condition",
+    tags: &[Tag::Grid, Tag::Graph],
+    new: || Box::new(Day20::new()),
+};
+
 #[derive(Debug)]
-pub struct Day20 {
-    live: bool,
+struct Day20 {
+    thresh: usize,
     grid: Grid<Cell>,
     start: Position,
     end: Position,
@@ -16,7 +27,7 @@ pub struct Day20 {
 impl Day20 {
     pub fn new() -> Day20 {
         Day20 {
-            live: false,
+            thresh: 100,
             grid: Grid::new(),
             start: (0, 0),
             end: (0, 0),
@@ -25,49 +36,34 @@ impl Day20 {
 }
 
 impl Solution for Day20 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let filename = "./data/day20.txt";
-        let lines = read_lines(filename)?;
-        self.live = if filename.contains("/data/") {
-            true
-        } else {
-            false
-        };
-        self.grid.width = if self.live { 141 } else { 15 };
-        self.grid.height = self.grid.width;
-        for (y, line) in lines.flatten().enumerate() {
-            self.grid.extend(
-                line.chars()
-                    .map(|c| Cell::from(c))
-                    .collect::<Result<Vec<_>, _>>()?,
-            );
-            if let Some(x) = line.chars().position(|c| c == 'S') {
-                self.start = (x, y);
-            }
-            if let Some(x) = line.chars().position(|c| c == 'E') {
-                self.end = (x, y);
-            }
-        }
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        let (grid, markers) = Grid::parse_with_markers(&read_to_string(input)?, "SE", Cell::from)?;
+        self.grid = grid;
+        self.start = markers.one('S')?;
+        self.end = markers.one('E')?;
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
-        let (maybe_base, forward_lows, backtracks) =
-            self.pathfind_with_backtrack(Cell::Empty, self.start, self.end);
-        let base = maybe_base.ok_or("no solution to input maze")?;
-        let (_, reverse_lows) = self.pathfind(Cell::Empty, self.end, self.start);
-        let mut cheats = FxHashMap::default();
-        let visited = backtrack(self.end, &backtracks);
-        for pos in &visited {
-            self.explore(2, *pos, base, &forward_lows, &reverse_lows, &mut cheats);
-        }
-        let thresh = if self.live { 100 } else { 50 };
-        let part1 = count_good_cheats(&cheats, base, thresh);
-        for pos in &visited {
-            self.explore(20, *pos, base, &forward_lows, &reverse_lows, &mut cheats);
-        }
-        let part2 = count_good_cheats(&cheats, base, thresh);
-        Ok(Answers::both(part1, part2))
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        Ok(self.count_cheats(2)?.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        Ok(self.count_cheats(20)?.into())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day20_01.txt",
+            Answers::both(1, 285),
+        )]
+    }
+
+    fn set_mode(&mut self, mode: Mode) {
+        self.thresh = match mode {
+            Mode::Live => 100,
+            Mode::Example => 50,
+        };
     }
 }
 
@@ -97,6 +93,26 @@ fn count_good_cheats(
 }
 
 impl Day20 {
+    /// Count the cheats of up to `max_steps` that save at least the threshold.
+    fn count_cheats(&self, max_steps: usize) -> Result<usize, Box<dyn Error>> {
+        let (maybe_base, forward_lows, backtracks) =
+            self.pathfind_with_backtrack(Cell::Empty, self.start, self.end);
+        let base = maybe_base.ok_or_else(|| AocError::unsolvable("no path through the maze"))?;
+        let (_, reverse_lows) = self.pathfind(Cell::Empty, self.end, self.start);
+        let mut cheats = FxHashMap::default();
+        for pos in &backtrack(self.end, &backtracks) {
+            self.explore(
+                max_steps,
+                *pos,
+                base,
+                &forward_lows,
+                &reverse_lows,
+                &mut cheats,
+            );
+        }
+        Ok(count_good_cheats(&cheats, base, self.thresh))
+    }
+
     fn explore(
         &self,
         max_steps: usize,
@@ -200,12 +216,19 @@ impl Display for Cell {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day20::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 20)?)?;
         let answers = solution.solve()?;
-        assert_eq!(answers, Answers::both(1441usize, 1021490usize));
+        assert_eq!(answers, Answers::both(1441, 1021490));
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
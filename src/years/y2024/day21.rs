@@ -1,12 +1,30 @@
-use crate::aoc::{read_lines, Answers, Position, Solution};
+use crate::aoc::answer::Answer;
+use crate::aoc::error::Line;
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_lines, Answers, Example, Input, Position, Solution};
 use itertools::Itertools;
 use rustc_hash::FxHashMap;
 use std::error::Error;
 use std::fmt::Display;
 
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 21,
+    title: "Keypad Conundrum",
+    tags: &[Tag::Graph],
+    new: || Box::new(Day21::new()),
+};
+
+/// A door code: the keys to press on the numeric keypad, ending in `A`, and its numeric part.
+#[derive(Debug)]
+struct Code {
+    keys: Vec<NumPad>,
+    value: usize,
+}
+
 #[derive(Debug)]
-pub struct Day21 {
-    codes: Vec<String>,
+struct Day21 {
+    codes: Vec<Code>,
     numpad_positions: [Position; 11],
 }
 
@@ -32,25 +50,40 @@ impl Day21 {
 }
 
 impl Solution for Day21 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let filename = "./data/day21.txt";
-        let lines = read_lines(filename)?;
-        for line in lines.flatten() {
-            self.codes.push(line);
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        for (i, text) in read_lines(input)?.enumerate() {
+            let text = text?;
+            let line = Line::new(i, &text);
+            let keys = text
+                .char_indices()
+                .map(|(j, c)| {
+                    NumPad::parse(c).ok_or_else(|| {
+                        line.error(&text[j..j + c.len_utf8()], format!("invalid key '{c}'"))
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let digits = text
+                .strip_suffix('A')
+                .ok_or_else(|| line.missing("the final 'A'"))?;
+            let value = line.parse::<usize>(digits)?;
+            self.codes.push(Code { keys, value });
         }
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
-        let mut memos = FxHashMap::default();
-        let mut part1 = 0;
-        let mut part2 = 0;
-        for code in &self.codes {
-            let num = code[0..code.len() - 1].parse::<usize>()?;
-            part1 += num * self.seq_len(code, 2, &mut memos).ok_or("invalid code")?;
-            part2 += num * self.seq_len(code, 25, &mut memos).ok_or("invalid code")?;
-        }
-        Ok(Answers::both(part1, part2))
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day21_01.txt",
+            Answers::part1(126384),
+        )]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        Ok(self.complexity(2).into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        Ok(self.complexity(25).into())
     }
 }
 
@@ -63,35 +96,38 @@ fn print_plan(code: &str, plan: &[DPad]) {
 }
 
 impl Day21 {
+    /// The sum of the complexities of the codes when typed through `depth` directional keypads.
+    fn complexity(&self, depth: usize) -> usize {
+        let mut memos = FxHashMap::default();
+        self.codes
+            .iter()
+            .map(|code| code.value * self.seq_len(&code.keys, depth, &mut memos))
+            .sum()
+    }
+
     fn seq_len(
         &self,
-        code: &str,
+        keys: &[NumPad],
         depth: usize,
         memos: &mut FxHashMap<(Vec<DPad>, usize), usize>,
-    ) -> Option<usize> {
-        Some(
-            format!("A{code}")
-                .chars()
-                .map(|c| NumPad::parse(c))
-                .collect::<Result<Vec<_>, _>>()
-                .ok()?
-                .iter()
-                .tuple_windows()
-                .map(|(start, end)| {
-                    self.numpad_paths(*start, *end)
-                        .iter()
-                        .map(|path| {
-                            if depth == 0 {
-                                path.len()
-                            } else {
-                                self.dpad_seq_len(path, depth, memos)
-                            }
-                        })
-                        .min()
-                        .unwrap_or(0)
-                })
-                .sum(),
-        )
+    ) -> usize {
+        std::iter::once(&NumPad::BA)
+            .chain(keys)
+            .tuple_windows()
+            .map(|(start, end)| {
+                self.numpad_paths(*start, *end)
+                    .iter()
+                    .map(|path| {
+                        if depth == 0 {
+                            path.len()
+                        } else {
+                            self.dpad_seq_len(path, depth, memos)
+                        }
+                    })
+                    .min()
+                    .unwrap_or(0)
+            })
+            .sum()
     }
 
     fn dpad_seq_len(
@@ -230,20 +266,20 @@ enum NumPad {
 }
 
 impl NumPad {
-    fn parse(c: char) -> Result<Self, String> {
+    fn parse(c: char) -> Option<Self> {
         match c {
-            '0' => Ok(Self::B0),
-            '1' => Ok(Self::B1),
-            '2' => Ok(Self::B2),
-            '3' => Ok(Self::B3),
-            '4' => Ok(Self::B4),
-            '5' => Ok(Self::B5),
-            '6' => Ok(Self::B6),
-            '7' => Ok(Self::B7),
-            '8' => Ok(Self::B8),
-            '9' => Ok(Self::B9),
-            'A' => Ok(Self::BA),
-            _ => Err("invalid char".into()),
+            '0' => Some(Self::B0),
+            '1' => Some(Self::B1),
+            '2' => Some(Self::B2),
+            '3' => Some(Self::B3),
+            '4' => Some(Self::B4),
+            '5' => Some(Self::B5),
+            '6' => Some(Self::B6),
+            '7' => Some(Self::B7),
+            '8' => Some(Self::B8),
+            '9' => Some(Self::B9),
+            'A' => Some(Self::BA),
+            _ => None,
         }
     }
 }
@@ -290,12 +326,33 @@ impl Display for DPad {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day21::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 21)?)?;
         let answers = solution.solve()?;
-        assert_eq!(answers, Answers::both(248108usize, 303836969158972usize));
+        assert_eq!(answers, Answers::both(248108, 303836969158972usize));
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
+
+    #[test]
+    fn invalid_codes() {
+        let error = |text| {
+            let mut solution = Day21::new();
+            solution
+                .parse_input(&Input::text(text))
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(error("029A\n02xA\n"), "line 2, column 3: invalid key 'x'");
+        assert_eq!(error("029A\n\n"), "line 2, column 1: missing the final 'A'");
+        assert_eq!(error("0291\n"), "line 1, column 5: missing the final 'A'");
+    }
 }
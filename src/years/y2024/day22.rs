@@ -1,11 +1,22 @@
 use itertools::Itertools;
 use rustc_hash::{FxHashMap, FxHashSet};
 
-use crate::aoc::{read_lines, Answers, Solution};
+use crate::aoc::answer::Answer;
+use crate::aoc::error::{AocError, Line};
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_lines, Answers, Example, Input, Solution};
 use std::error::Error;
 
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 22,
+    title: "Monkey Market",
+    tags: &[Tag::Simulation],
+    new: || Box::new(Day22::new()),
+};
+
 #[derive(Debug)]
-pub struct Day22 {
+struct Day22 {
     seeds: Vec<usize>,
 }
 
@@ -16,23 +27,31 @@ impl Day22 {
 }
 
 impl Solution for Day22 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let filename = "./data/day22.txt";
-        let lines = read_lines(filename)?;
-        self.seeds = lines
-            .flatten()
-            .map(|l| l.parse::<usize>())
-            .collect::<Result<Vec<_>, _>>()?;
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        for (i, text) in read_lines(input)?.enumerate() {
+            let text = text?;
+            self.seeds.push(Line::new(i, &text).parse::<usize>(&text)?);
+        }
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("test_cases/2024/day22_01.txt", Answers::part1(37327623)),
+            Example::new("test_cases/2024/day22_02.txt", Answers::part2(23)),
+        ]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
         let part1: usize = self
             .seeds
             .iter()
-            .map(|&s| Prng::new(s).nth(1999))
-            .flatten()
+            .filter_map(|&s| Prng::new(s).nth(1999))
             .sum();
+        Ok(part1.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
         let part2 = *self
             .seeds
             .iter()
@@ -54,8 +73,8 @@ impl Solution for Day22 {
             })
             .values()
             .max()
-            .ok_or("no payoffs found")?;
-        Ok(Answers::both(part1, part2))
+            .ok_or_else(|| AocError::unsolvable("no payoffs found"))?;
+        Ok(part2.into())
     }
 }
 
@@ -85,12 +104,19 @@ impl Iterator for Prng {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day22::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 22)?)?;
         let answers = solution.solve()?;
-        assert_eq!(answers, Answers::both(12979353889usize, 1449isize));
+        assert_eq!(answers, Answers::both(12979353889usize, 1449));
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
@@ -1,11 +1,22 @@
-use crate::aoc::{read_lines, Answers, Solution};
+use crate::aoc::answer::Answer;
+use crate::aoc::error::Line;
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_lines, Answers, Example, Input, Solution};
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::hash_map::Keys;
 use std::error::Error;
 use std::hash::Hash;
 
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 23,
+    title: "LAN Party",
+    tags: &[Tag::Graph],
+    new: || Box::new(Day23::new()),
+};
+
 #[derive(Debug)]
-pub struct Day23 {
+struct Day23 {
     network: Graph<String>,
 }
 
@@ -18,27 +29,36 @@ impl Day23 {
 }
 
 impl Solution for Day23 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let filename = "./data/day23.txt";
-        let lines = read_lines(filename)?;
-        for line in lines.flatten() {
-            let mut parts = line.split("-").map(|s| s.to_owned());
-            self.network.link(
-                parts.next().ok_or("missing first part")?,
-                parts.next().ok_or("missing second part")?,
-            );
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        for (i, text) in read_lines(input)?.enumerate() {
+            let text = text?;
+            let line = Line::new(i, &text);
+            let (left, right) = text
+                .split_once("-")
+                .ok_or_else(|| line.missing("a second computer"))?;
+            self.network.link(left.to_owned(), right.to_owned());
         }
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day23_01.txt",
+            Answers::both(7, "co,de,ka,ta"),
+        )]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
         let cliques = self.network.cliques(3);
         let part1 = cliques
             .iter()
             .filter(|&c| c.iter().any(|s| s.starts_with("t")))
             .count();
-        let part2 = self.network.largest_clique().join(",");
-        Ok(Answers::both(part1, part2))
+        Ok(part1.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        Ok(self.network.largest_clique().join(",").into())
     }
 }
 
@@ -172,15 +192,22 @@ impl<T: Hash + Eq + Clone + Ord> Graph<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day23::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 23)?)?;
         let answers = solution.solve()?;
         assert_eq!(
             answers,
-            Answers::both(1110usize, "ej,hm,ks,ms,ns,rb,rq,sc,so,un,vb,vd,wd")
+            Answers::both(1110, "ej,hm,ks,ms,ns,rb,rq,sc,so,un,vb,vd,wd")
         );
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
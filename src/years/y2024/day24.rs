@@ -1,11 +1,21 @@
-use crate::aoc::{Answers, Solution};
+use crate::aoc::answer::Answer;
+use crate::aoc::error::AocError;
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_to_string, Answers, Example, Input, Solution};
 use rustc_hash::FxHashMap;
 use std::collections::VecDeque;
 use std::error::Error;
-use std::fs::read_to_string;
+
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 24,
+    title: "Crossed Wires",
+    tags: &[Tag::Graph, Tag::Simulation],
+    new: || Box::new(Day24::new()),
+};
 
 #[derive(Debug)]
-pub struct Day24 {
+struct Day24 {
     wires: FxHashMap<String, bool>,
     gates: VecDeque<Gate>,
 }
@@ -20,34 +30,39 @@ impl Day24 {
 }
 
 impl Solution for Day24 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let filename = "./data/day24.txt";
-        let content = read_to_string(filename)?;
-        let mut parts = content.split("\n\n");
-        let raw_wires = parts.next().ok_or("missing wire states")?;
-        let raw_gates = parts.next().ok_or("missing gate layout")?;
-        let raw_gates = &raw_gates[..raw_gates.len() - 1];
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        let content = read_to_string(input)?;
+        let (raw_wires, raw_gates) = content.split_once("\n\n").ok_or_else(|| {
+            AocError::invalid("expected wires and gates separated by a blank line")
+        })?;
         for line in raw_wires.split("\n") {
-            let mut parts = line.split(": ");
-            let label = parts.next().ok_or("missing wire label")?;
-            let value = parts.next().ok_or("missing wire value")?;
+            let (label, value) = line
+                .split_once(": ")
+                .ok_or_else(|| AocError::at(&content, line, "expected a wire and its value"))?;
             self.wires.insert(
                 label.to_string(),
                 match value {
                     "1" => Ok(true),
                     "0" => Ok(false),
-                    _ => Err("invalid wire value"),
+                    _ => Err(AocError::at(&content, value, "invalid wire value")),
                 }?,
             );
         }
-        for line in raw_gates.split("\n") {
-            let mut parts = line.split(" -> ");
-            let operands = parts.next().ok_or("missing operands")?;
-            let output = parts.next().ok_or("missing output")?.to_string();
+        for line in raw_gates.lines() {
+            let error = |token, message| AocError::at(&content, token, message);
+            let (operands, output) = line
+                .split_once(" -> ")
+                .ok_or_else(|| error(line, "expected a gate and its output"))?;
+            let output = output.to_string();
             let mut parts = operands.split(" ");
-            let left = parts.next().ok_or("missing left operand")?.to_string();
-            let operator = parts.next().ok_or("missing operator")?;
-            let right = parts.next().ok_or("missing right operand")?.to_string();
+            let left = parts.next().unwrap_or_default().to_string();
+            let operator = parts
+                .next()
+                .ok_or_else(|| error(operands, "missing operator"))?;
+            let right = parts
+                .next()
+                .ok_or_else(|| error(operands, "missing right operand"))?
+                .to_string();
             self.gates.push_back(match operator {
                 "AND" => Ok(Gate {
                     left,
@@ -67,15 +82,26 @@ impl Solution for Day24 {
                     output,
                     op: Op::Xor,
                 }),
-                _ => Err("invalid operator"),
+                _ => Err(AocError::at(&content, operator, "invalid operator")),
             }?);
         }
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
-        let sorted_gates = self.simulate();
-        let part1 = self.read('z');
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("test_cases/2024/day24_01.txt", Answers::part1(4)),
+            Example::new("test_cases/2024/day24_02.txt", Answers::part1(2024)),
+        ]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let (_, wires) = self.simulate();
+        Ok(read(&wires, 'z').into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        let (sorted_gates, _) = self.simulate();
         // We are assuming that no gates have been switched to "equivalent" positions, e.g.
         // swapping two carry outputs. This is in principle detectable by tracking which input gate
         // we started at but at least in my input there were no such swaps.
@@ -128,41 +154,38 @@ impl Solution for Day24 {
         let mut bad_outputs: Vec<String> =
             bad_gates.into_iter().map(|g| g.output.clone()).collect();
         bad_outputs.sort();
-        let part2 = bad_outputs.join(",");
-        Ok(Answers::both(part1, part2))
+        Ok(bad_outputs.join(",").into())
     }
 }
 
 impl Day24 {
-    fn simulate(&mut self) -> Vec<Gate> {
+    /// Evaluate every gate, returning the gates in the order they were evaluated along with the
+    /// final state of the wires.
+    fn simulate(&self) -> (Vec<Gate>, FxHashMap<String, bool>) {
         let mut sorted_gates = Vec::new();
+        let mut wires = self.wires.clone();
         let mut gates = self.gates.clone();
         while let Some(gate) = gates.pop_front() {
-            if !gate.eval(&mut self.wires) {
+            if !gate.eval(&mut wires) {
                 gates.push_back(gate);
             } else {
                 sorted_gates.push(gate)
             }
         }
-        sorted_gates
+        (sorted_gates, wires)
     }
+}
 
-    fn read(&self, c: char) -> usize {
-        let mut wires: Vec<String> = self
-            .wires
-            .keys()
-            .filter(|k| k.chars().nth(0).unwrap() == c)
-            .cloned()
-            .collect();
-        wires.sort();
-        wires.iter().enumerate().fold(0, |a, (i, wire)| {
-            a + if *self.wires.get(wire).unwrap_or(&false) {
-                2usize.pow(i as u32)
-            } else {
-                0
-            }
-        })
-    }
+fn read(wires: &FxHashMap<String, bool>, c: char) -> usize {
+    let mut names: Vec<&String> = wires.keys().filter(|k| k.starts_with(c)).collect();
+    names.sort();
+    names.iter().enumerate().fold(0, |a, (i, &wire)| {
+        a + if *wires.get(wire).unwrap_or(&false) {
+            2usize.pow(i as u32)
+        } else {
+            0
+        }
+    })
 }
 
 #[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Clone, Debug)]
@@ -214,10 +237,12 @@ impl Gate {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day24::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 24)?)?;
         let answers = solution.solve()?;
         assert_eq!(
             answers,
@@ -225,4 +250,9 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
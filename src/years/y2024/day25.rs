@@ -1,8 +1,18 @@
-use crate::aoc::{Answers, Solution};
-use std::{error::Error, fs::read_to_string};
+use crate::aoc::answer::Answer;
+use crate::aoc::puzzle::{Puzzle, Tag};
+use crate::aoc::{read_to_string, Answers, Example, Input, Solution};
+use std::error::Error;
+
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2024,
+    day: 25,
+    title: "Code Chronicle",
+    tags: &[Tag::Grid],
+    new: || Box::new(Day25::new()),
+};
 
 #[derive(Debug)]
-pub struct Day25 {
+struct Day25 {
     locks: Vec<Levels>,
     keys: Vec<Levels>,
 }
@@ -19,9 +29,8 @@ impl Day25 {
 type Levels = [u8; 5];
 
 impl Solution for Day25 {
-    fn parse_input(&mut self) -> Result<(), Box<dyn Error>> {
-        let filename = "./data/day25.txt";
-        let raw = read_to_string(filename)?;
+    fn parse_input(&mut self, input: &Input) -> Result<(), Box<dyn Error>> {
+        let raw = read_to_string(input)?;
         let parts = raw.split("\n\n");
         for part in parts {
             if part.starts_with("#") {
@@ -58,7 +67,14 @@ impl Solution for Day25 {
         Ok(())
     }
 
-    fn solve(&mut self) -> Result<Answers, Box<dyn Error>> {
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            "test_cases/2024/day25_01.txt",
+            Answers::part1(3),
+        )]
+    }
+
+    fn part1(&mut self) -> Result<Answer, Box<dyn Error>> {
         let mut part1 = 0;
         for lock in &self.locks {
             for key in &self.keys {
@@ -67,7 +83,11 @@ impl Solution for Day25 {
                 }
             }
         }
-        Ok(Answers::both(part1, "Yay!"))
+        Ok(part1.into())
+    }
+
+    fn part2(&mut self) -> Result<Answer, Box<dyn Error>> {
+        Ok("Yay!".into())
     }
 }
 
@@ -83,12 +103,19 @@ fn possible_fit(lock: &Levels, key: &Levels) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc::{check_examples, default_input};
     #[test]
+    #[ignore = "needs the private input"]
     fn solution() -> Result<(), Box<dyn Error>> {
         let mut solution = Day25::new();
-        solution.parse_input()?;
+        solution.parse_input(&default_input(2024, 25)?)?;
         let answers = solution.solve()?;
         assert_eq!(answers, Answers::both(3065, "Yay!"));
         Ok(())
     }
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn Error>> {
+        check_examples(&PUZZLE)
+    }
 }
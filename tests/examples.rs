@@ -0,0 +1,35 @@
+use aoc2024::aoc::{check_examples, read_to_string, Solution};
+use aoc2024::years;
+use std::error::Error;
+
+/// Whether every example has been filled in, which a freshly scaffolded day's has not.
+fn has_examples(solution: &dyn Solution) -> Result<bool, Box<dyn Error>> {
+    for example in solution.examples() {
+        if read_to_string(&example.input())?.trim().is_empty() {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+#[test]
+fn every_puzzle_solves_its_examples() -> Result<(), Box<dyn Error>> {
+    let mut checked = 0;
+    for puzzle in years::puzzles() {
+        if has_examples(&*puzzle.solution())? {
+            check_examples(puzzle).map_err(|e| format!("{puzzle}: {e}"))?;
+            checked += 1;
+        }
+    }
+    assert!(checked > 0);
+    Ok(())
+}
+
+#[test]
+fn puzzles_are_found_by_year_and_day() {
+    for puzzle in years::puzzles() {
+        let found = years::find(puzzle.year, puzzle.day).map(|p| (p.year, p.day));
+        assert_eq!(found, Some((puzzle.year, puzzle.day)));
+    }
+    assert!(years::find(2024, 26).is_none());
+}